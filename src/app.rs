@@ -1,85 +1,184 @@
+pub mod event;
+pub mod filter;
 pub mod helper;
+pub mod input;
 pub mod menu;
+pub mod reminder;
 pub mod selection;
 pub mod tabs;
 
-use std::io::{stdin, stdout, Write};
+use std::cell::RefCell;
+use std::io::{self, stdout, Write};
+use std::mem;
 use std::path::Path;
+use std::rc::Rc;
 
-use crate::app::helper::{Cursor, Print};
-use crate::app::menu::{Menu, MenuItem};
-use crate::app::tabs::done::Done;
+use crate::app::event::{Event, EventHandler, Events};
+use crate::app::helper::{Cursor, Print, Screen};
+use crate::app::menu::Menu;
+use crate::app::reminder::Reminder;
 use crate::app::tabs::todo::Todo;
 use crate::config::Action;
 use crate::config::Config;
+use crate::storage::{self, ParseError};
 
-use termion;
-use termion::event::Key;
-use termion::input::TermRead;
 use termion::raw::IntoRawMode;
 
 pub struct App<'a> {
+    config: Config<'a>,
+    path: &'a Path,
     menu: Menu<'a>,
-    todo: Todo<'a>,
-    done: Done,
-    key_mapping: &'a Vec<(Action, char)>,
+    todo: Rc<RefCell<Todo<'a>>>,
+    reminder: Option<Reminder<'a>>,
+    banner: Option<String>,
+}
+
+fn get_load_banner(errors: &[ParseError], path: &Path) -> Option<String> {
+    if errors.is_empty() {
+        return None;
+    }
+
+    let details: Vec<String> = errors.iter().map(|error| error.to_string()).collect();
+
+    Some(format!(
+        "Skipped {count} malformed line(s), moved to '{rejected}': {details}",
+        count = errors.len(),
+        rejected = storage::get_rejected_path(path).display(),
+        details = details.join("; ")
+    ))
 }
 
 impl<'a> App<'a> {
-    pub fn init(config: &'a Config, path: &'a Path) -> Self {
-        // TODO: maybe there is a way to get rid of &config.key_mapping somewhow maybe just pass a
-        // whole config or not
-        let menu = Menu::init(&config.selection_style, &config.key_mapping);
-        let todo = Todo::init(&config.selection_style, &config.key_mapping, &path);
-        let done = Done::init(&todo.todo_list);
-
-        App {
+    pub fn init(config: Config<'a>, path: &'a Path) -> Result<Self, String> {
+        let store = storage::open(&config.storage_backend, path)?;
+        let todo = Todo::init(store).map_err(|err| format!("Couldn't read todo list: {err}"))?;
+        let banner = get_load_banner(todo.get_load_errors(), path);
+        let todo = Rc::new(RefCell::new(todo));
+        let menu = Menu::init(tabs::init(&todo), &config);
+        let reminder = if config.remind_unfinished {
+            Reminder::init(Rc::clone(&todo), path)
+        } else {
+            None
+        };
+
+        Ok(App {
+            config,
+            path,
             menu,
             todo,
-            done,
-            key_mapping: &config.key_mapping,
-        }
+            reminder,
+            banner,
+        })
     }
 
-    pub fn run(&mut self) {
-        Cursor::hide();
-        Print::prepare();
-
-        let stdin = stdin();
-        let mut screen = termion::screen::AlternateScreen::from(stdout().into_raw_mode().unwrap());
+    fn render(&self) {
+        match &self.reminder {
+            Some(reminder) => reminder.render(&self.config),
+            None => self.menu.render(&self.config),
+        }
 
-        let keys = stdin.keys();
+        if let Some(banner) = &self.banner {
+            Print::banner(banner);
+        }
+    }
 
-        // TODO: probably make into a one `self` method
-        self.menu.render();
-        self.todo.render();
+    fn listen_action(&mut self, action: &Action) {
+        if self.banner.take().is_some() {
+            Screen::clear();
+        }
 
-        for key in keys {
-            let key = key.unwrap();
+        if let Some(reminder) = &mut self.reminder {
+            reminder.handle_event(&Event::Action(action.clone()), &mut self.config);
 
-            if key == Key::Char(Action::get_action_char(self.key_mapping, Action::Quit)) {
-                break;
+            if reminder.is_finished() {
+                self.reminder = None;
+                Screen::clear();
             }
 
-            // TODO: rename `listen_keys`
-            self.menu.listen_keys(&key);
+            return;
+        }
+
+        self.menu
+            .handle_event(&Event::Action(action.clone()), &mut self.config);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::Action(action) => self.listen_action(action),
+            Event::Tick => {
+                let is_hidden = self.menu.is_hidden();
 
-            match self.menu.selected_menu {
-                MenuItem::Todo => {
-                    self.todo.listen_keys(&key);
-                    self.todo.render();
+                if self.reminder.is_none() {
+                    self.menu.handle_event(event, &mut self.config);
                 }
-                MenuItem::Done => {
-                    self.done.render();
+
+                return is_hidden != self.menu.is_hidden();
+            }
+            Event::Resize => {
+                Screen::clear();
+                self.menu.handle_event(event, &mut self.config);
+            }
+            Event::FileChanged => {
+                self.menu.handle_event(event, &mut self.config);
+
+                if let Some(banner) =
+                    get_load_banner(self.todo.borrow().get_load_errors(), self.path)
+                {
+                    self.banner = Some(banner);
                 }
-                _ => {}
             }
+        }
+
+        if let Some(error) = self.todo.borrow_mut().take_save_error() {
+            self.banner = Some(error);
+        }
+
+        true
+    }
+
+    fn listen_events(&mut self, events: &mut Events, screen: &mut impl Write) -> io::Result<()> {
+        self.render();
+        screen.flush()?;
 
-            self.menu.render();
+        loop {
+            let event = match events.next_event(&self.config.key_mapping) {
+                Ok(event) => event,
+                Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => return Ok(()),
+                Err(err) => return Err(err),
+            };
 
-            screen.flush().unwrap();
+            if event == Event::Action(Action::Quit) {
+                return Ok(());
+            }
+
+            if self.handle_event(&event) {
+                self.render();
+                screen.flush()?;
+            }
         }
+    }
+
+    pub fn run(&mut self) -> Result<(), String> {
+        let mut events = Events::init(&self.config.storage_backend.get_path(self.path))
+            .map_err(|err| format!("Couldn't listen to events: {err}"))?;
+        let raw_terminal = stdout()
+            .into_raw_mode()
+            .map_err(|err| format!("Couldn't switch the terminal to raw mode: {err}"))?;
+
+        Cursor::hide();
+        Print::prepare();
+
+        let mut screen = termion::screen::AlternateScreen::from(raw_terminal);
+        let result = self.listen_events(&mut events, &mut screen);
 
         Cursor::show();
+
+        // --- Note ---
+        // termion panics when it restores a terminal which hung up, there is nothing left to restore
+        if screen.suspend_raw_mode().is_err() {
+            mem::forget(screen);
+        }
+
+        result.map_err(|err| format!("Couldn't use the terminal: {err}"))
     }
 }
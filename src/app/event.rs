@@ -0,0 +1,239 @@
+use crate::app::input::{Input, PendingKeys};
+use crate::config::keys::Binding;
+use crate::config::{Action, Config};
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::os::fd::AsRawFd;
+use std::os::unix::net::UnixStream;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+const TICK_INTERVAL: u64 = 100;
+const RESIZE_POLL_INTERVAL: u64 = 100;
+const FILE_POLL_INTERVAL: u64 = 1000;
+const WAKE_BUFFER_SIZE: usize = 64;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    Action(Action),
+    Tick,
+    Resize,
+    FileChanged,
+}
+
+pub trait EventHandler {
+    fn handle_event(&mut self, _event: &Event, _config: &mut Config) {}
+}
+
+impl<T: EventHandler + ?Sized> EventHandler for Rc<RefCell<T>> {
+    fn handle_event(&mut self, event: &Event, config: &mut Config) {
+        self.borrow_mut().handle_event(event, config);
+    }
+}
+
+#[derive(Clone)]
+struct Sender {
+    sender: mpsc::Sender<Event>,
+    wake: Arc<UnixStream>,
+}
+
+impl Sender {
+    fn send(&self, event: Event) -> bool {
+        if self.sender.send(event).is_err() {
+            return false;
+        }
+
+        (&*self.wake).write_all(&[0]).is_ok()
+    }
+}
+
+fn spawn_ticker(sender: Sender) {
+    thread::spawn(move || loop {
+        thread::sleep(Duration::from_millis(TICK_INTERVAL));
+
+        if !sender.send(Event::Tick) {
+            break;
+        }
+    });
+}
+
+fn spawn_resize_watcher(sender: Sender) {
+    thread::spawn(move || {
+        let Ok(mut screen_size) = termion::terminal_size() else {
+            return;
+        };
+
+        loop {
+            thread::sleep(Duration::from_millis(RESIZE_POLL_INTERVAL));
+
+            let Ok(new_screen_size) = termion::terminal_size() else {
+                break;
+            };
+
+            if new_screen_size != screen_size {
+                screen_size = new_screen_size;
+
+                if !sender.send(Event::Resize) {
+                    break;
+                }
+            }
+        }
+    });
+}
+
+fn get_modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+fn spawn_file_watcher(sender: Sender, path: PathBuf) {
+    thread::spawn(move || {
+        let mut modified = get_modified(&path);
+
+        loop {
+            thread::sleep(Duration::from_millis(FILE_POLL_INTERVAL));
+
+            let new_modified = get_modified(&path);
+
+            if new_modified != modified {
+                modified = new_modified;
+
+                if !sender.send(Event::FileChanged) {
+                    break;
+                }
+            }
+        }
+    });
+}
+
+fn poll(fds: &mut [libc::pollfd]) -> io::Result<()> {
+    // SAFETY: `fds` is a valid pointer to `fds.len()` of `pollfd`
+    match unsafe { libc::poll(fds.as_mut_ptr(), fds.len() as libc::nfds_t, -1) } {
+        -1 => {
+            let error = io::Error::last_os_error();
+
+            // --- Note ---
+            // Signals like SIGWINCH interrupt the poll, which is not an error for us
+            match error.kind() {
+                io::ErrorKind::Interrupted => Ok(()),
+                _ => Err(error),
+            }
+        }
+        _ => Ok(()),
+    }
+}
+
+pub struct Events {
+    input: Input,
+    pending_keys: PendingKeys,
+    actions: VecDeque<Action>,
+    receiver: Receiver<Event>,
+    wake: UnixStream,
+}
+
+impl Events {
+    pub fn init(path: &Path) -> io::Result<Self> {
+        let (sender, receiver) = mpsc::channel();
+        let (wake, wake_sender) = UnixStream::pair()?;
+
+        wake.set_nonblocking(true)?;
+
+        let sender = Sender {
+            sender,
+            wake: Arc::new(wake_sender),
+        };
+
+        spawn_ticker(sender.clone());
+        spawn_resize_watcher(sender.clone());
+        spawn_file_watcher(sender, path.to_owned());
+
+        Ok(Events {
+            input: Input::init(),
+            pending_keys: PendingKeys::init(),
+            actions: VecDeque::new(),
+            receiver,
+            wake,
+        })
+    }
+
+    fn clear_wake(&self) -> io::Result<()> {
+        let mut bytes = [0; WAKE_BUFFER_SIZE];
+
+        loop {
+            match (&self.wake).read(&mut bytes) {
+                Ok(0) => return Ok(()),
+                Ok(_) => {}
+                Err(error) if error.kind() == io::ErrorKind::WouldBlock => return Ok(()),
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    fn next_queued(&self) -> Option<Event> {
+        let mut is_tick = false;
+
+        loop {
+            match self.receiver.try_recv() {
+                Ok(Event::Tick) => is_tick = true,
+                Ok(event) => return Some(event),
+                Err(TryRecvError::Empty | TryRecvError::Disconnected) => break,
+            }
+        }
+
+        is_tick.then_some(Event::Tick)
+    }
+
+    pub fn next_event(&mut self, key_mapping: &[(Action, Vec<Binding>)]) -> io::Result<Event> {
+        loop {
+            if let Some(action) = self.actions.pop_front() {
+                return Ok(Event::Action(action));
+            }
+
+            if let Some(key) = self.input.parse() {
+                self.actions
+                    .extend(self.pending_keys.push(key, key_mapping));
+
+                continue;
+            }
+
+            match self.next_queued() {
+                Some(Event::Tick) => {
+                    return Ok(self
+                        .pending_keys
+                        .expire(key_mapping)
+                        .map_or(Event::Tick, Event::Action));
+                }
+                Some(event) => return Ok(event),
+                None => {}
+            }
+
+            let mut fds = [libc::STDIN_FILENO, self.wake.as_raw_fd()].map(|fd| libc::pollfd {
+                fd,
+                events: libc::POLLIN,
+                revents: 0,
+            });
+
+            poll(&mut fds)?;
+
+            // --- Note ---
+            // Closed input is reported without `POLLIN`, the poll would return right away forever
+            if fds[0].revents & libc::POLLIN != 0 {
+                self.input.read()?;
+            } else if fds[0].revents & (libc::POLLHUP | libc::POLLERR | libc::POLLNVAL) != 0 {
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+
+            if fds[1].revents & libc::POLLIN != 0 {
+                self.clear_wake()?;
+            }
+        }
+    }
+}
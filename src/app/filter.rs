@@ -0,0 +1,147 @@
+use crate::app::helper::{Cursor, Screen};
+use crate::app::tabs::todo::{Tag, TodoItem};
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use rustyline::completion::Completer;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::Validator;
+use rustyline::{Context, Editor, Helper, Result as RLResult};
+
+const FILTER_PROMPT: &str = "filter: ";
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Filter {
+    tags: Vec<Tag>,
+    names: Vec<String>,
+}
+
+impl Filter {
+    pub fn parse(input: &str) -> Self {
+        let mut filter = Filter::default();
+
+        for word in input.split_whitespace() {
+            match Tag::parse(word) {
+                Some(tag) => filter.tags.push(tag),
+                None => filter.names.push(word.to_lowercase()),
+            }
+        }
+
+        filter
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty() && self.names.is_empty()
+    }
+
+    pub fn get_tags(&self) -> &[Tag] {
+        &self.tags
+    }
+
+    pub fn matches(&self, item: &TodoItem) -> bool {
+        let item_tags = item.get_tags();
+
+        self.tags
+            .iter()
+            .all(|tag| item_tags.iter().any(|item_tag| item_tag.matches(tag)))
+            && self.names.iter().all(|name| {
+                item_tags
+                    .iter()
+                    .any(|item_tag| item_tag.name.to_lowercase() == *name)
+            })
+    }
+
+    pub fn prompt(&self, todo_list: &[TodoItem]) -> Option<Filter> {
+        let (_, y_size) = Screen::get_size();
+        let tags: BTreeSet<String> = todo_list
+            .iter()
+            .flat_map(|item| item.get_tags())
+            .map(|tag| tag.to_string())
+            .collect();
+
+        let mut rl = Editor::<TagCompleter>::new().unwrap();
+
+        rl.set_helper(Some(TagCompleter {
+            tags: tags.into_iter().collect(),
+        }));
+
+        Cursor::show();
+        Cursor::place(1, y_size.saturating_sub(1));
+
+        let input = rl
+            .readline_with_initial(FILTER_PROMPT, (&self.to_string(), ""))
+            .ok();
+
+        Cursor::hide();
+        Screen::clear();
+
+        input.map(|input| Filter::parse(&input))
+    }
+}
+
+impl fmt::Display for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let words: Vec<String> = self
+            .tags
+            .iter()
+            .map(|tag| tag.to_string())
+            .chain(self.names.iter().cloned())
+            .collect();
+
+        write!(f, "{}", words.join(" "))
+    }
+}
+
+struct TagCompleter {
+    tags: Vec<String>,
+}
+
+impl Completer for TagCompleter {
+    type Candidate = String;
+
+    fn complete(&self, line: &str, pos: usize, _: &Context<'_>) -> RLResult<(usize, Vec<String>)> {
+        let start = line[..pos].rfind(' ').map_or(0, |index| index + 1);
+        let word = line[start..pos].to_lowercase();
+        let candidates = self
+            .tags
+            .iter()
+            .filter(|tag| tag.to_lowercase().starts_with(&word))
+            .cloned()
+            .collect();
+
+        Ok((start, candidates))
+    }
+}
+
+impl Hinter for TagCompleter {
+    type Hint = String;
+}
+
+impl Highlighter for TagCompleter {}
+
+impl Validator for TagCompleter {}
+
+impl Helper for TagCompleter {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_matches() {
+        let mut item = TodoItem::new(0, "Fix build +mindr @work");
+
+        assert!(Filter::parse("").matches(&item));
+        assert!(Filter::parse("+Mindr").matches(&item));
+        assert!(Filter::parse("+mindr @work").matches(&item));
+        assert!(Filter::parse("work").matches(&item));
+        assert!(!Filter::parse("+mindr @home").matches(&item));
+        assert!(!Filter::parse("@mindr").matches(&item));
+
+        item.description = String::from("Fix build");
+
+        assert!(!Filter::parse("+mindr").matches(&item));
+    }
+}
@@ -10,7 +10,53 @@ impl Print {
     }
 
     pub fn finsih() {
-        println!("");
+        println!();
+    }
+
+    pub fn scroll_indicator(start: usize, end: usize, total: usize) {
+        let (x_size, y_size) = Screen::get_size();
+        let up = if start > 0 { "↑" } else { " " };
+        let down = if end < total { "↓" } else { " " };
+        let text = format!(
+            "{up}{down} {first}-{last}/{total} ",
+            first = start + 1,
+            last = end
+        );
+        let x_offset = x_size.saturating_sub(text.chars().count() as u16) + 1;
+
+        print!(
+            "{goto}{clear}{text}",
+            goto = termion::cursor::Goto(x_offset, y_size),
+            clear = termion::clear::UntilNewline,
+        );
+
+        Cursor::reset();
+    }
+
+    pub fn status(string: &str) {
+        let (_, y_size) = Screen::get_size();
+
+        print!(
+            "{goto}{faint}{string}{reset}",
+            goto = termion::cursor::Goto(1, y_size),
+            faint = termion::style::Faint,
+            reset = termion::style::Reset,
+        );
+
+        Cursor::reset();
+    }
+
+    pub fn banner(string: &str) {
+        let (x_size, y_size) = Screen::get_size();
+        let text: String = string.chars().take(x_size as usize).collect();
+
+        print!(
+            "{goto}{clear}{invert}{text}{reset}",
+            goto = termion::cursor::Goto(1, y_size),
+            clear = termion::clear::CurrentLine,
+            invert = termion::style::Invert,
+            reset = termion::style::Reset,
+        );
     }
 }
 
@@ -41,6 +87,16 @@ impl Screen {
         print!("{}", termion::clear::All);
     }
 
+    pub fn clear_rows(from: u16, to: u16) {
+        for y in from..=to {
+            print!(
+                "{goto}{clear}",
+                goto = termion::cursor::Goto(1, y),
+                clear = termion::clear::CurrentLine,
+            );
+        }
+    }
+
     pub fn get_size() -> (u16, u16) {
         termion::terminal_size().expect("Couldn't get the terminal window size")
     }
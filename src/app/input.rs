@@ -0,0 +1,168 @@
+use crate::config::keys::Binding;
+use crate::config::Action;
+
+use std::collections::VecDeque;
+use std::io;
+use std::time::{Duration, Instant};
+
+use termion::event::{parse_event, Event, Key};
+
+const READ_SIZE: usize = 1024;
+const KEY_SEQUENCE_TIMEOUT: u64 = 1000;
+
+// --- Note ---
+// Unlike `stdin().keys()` input only reads what is already there, waiting for it is left to the
+// event loop. Bytes are read straight from the file descriptor, which keeps `rustyline` prompts
+// working since nothing is left in the std buffer
+pub struct Input {
+    buffer: VecDeque<u8>,
+}
+
+impl Input {
+    pub fn init() -> Self {
+        Input {
+            buffer: VecDeque::new(),
+        }
+    }
+
+    pub fn read(&mut self) -> io::Result<()> {
+        let mut bytes = [0; READ_SIZE];
+
+        // SAFETY: `bytes` is a valid buffer of `READ_SIZE` length
+        let count = unsafe { libc::read(libc::STDIN_FILENO, bytes.as_mut_ptr().cast(), READ_SIZE) };
+
+        match count {
+            0 => return Err(io::ErrorKind::UnexpectedEof.into()),
+            count if count < 0 => return Err(io::Error::last_os_error()),
+            _ => {}
+        }
+
+        self.buffer.extend(&bytes[..count as usize]);
+
+        Ok(())
+    }
+
+    pub fn parse(&mut self) -> Option<Key> {
+        while let Some(first) = self.buffer.pop_front() {
+            let bytes: Vec<u8> = self.buffer.drain(..).collect();
+            let mut rest = bytes.into_iter().map(Ok);
+
+            let event = parse_event(first, &mut rest);
+
+            self.buffer = rest.map(|byte: io::Result<u8>| byte.unwrap()).collect();
+
+            if let Ok(Event::Key(key)) = event {
+                return Some(key);
+            }
+        }
+
+        None
+    }
+}
+
+pub struct PendingKeys {
+    keys: Vec<Key>,
+    last_key_time: Instant,
+}
+
+impl PendingKeys {
+    pub fn init() -> Self {
+        PendingKeys {
+            keys: Vec::new(),
+            last_key_time: Instant::now(),
+        }
+    }
+
+    fn flush(&mut self, key_mapping: &[(Action, Vec<Binding>)]) -> Option<Action> {
+        let action = Action::find(key_mapping, &self.keys);
+
+        self.keys.clear();
+
+        action
+    }
+
+    pub fn push(&mut self, key: Key, key_mapping: &[(Action, Vec<Binding>)]) -> Vec<Action> {
+        self.keys.push(key);
+        self.last_key_time = Instant::now();
+
+        if Action::is_prefix(key_mapping, &self.keys) {
+            return Vec::new();
+        }
+
+        if let Some(action) = Action::find(key_mapping, &self.keys) {
+            self.keys.clear();
+
+            return vec![action];
+        }
+
+        if self.keys.len() == 1 {
+            self.keys.clear();
+
+            return Vec::new();
+        }
+
+        self.keys.pop();
+
+        let mut actions: Vec<Action> = self.flush(key_mapping).into_iter().collect();
+
+        actions.extend(self.push(key, key_mapping));
+
+        actions
+    }
+
+    pub fn expire(&mut self, key_mapping: &[(Action, Vec<Binding>)]) -> Option<Action> {
+        let timeout = Duration::from_millis(KEY_SEQUENCE_TIMEOUT);
+
+        if self.keys.is_empty() || self.last_key_time.elapsed() < timeout {
+            return None;
+        }
+
+        self.flush(key_mapping)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{keys, Config};
+
+    #[test]
+    fn test_pending_keys() {
+        let mut config = Config::default();
+        let mut pending_keys = PendingKeys::init();
+
+        for (action, bindings) in config.key_mapping.iter_mut() {
+            if *action == Action::Bottom {
+                *bindings = keys::parse_bindings("g").unwrap();
+            }
+        }
+
+        let key_mapping = &config.key_mapping;
+
+        assert_eq!(pending_keys.push(Key::Up, key_mapping), vec![Action::Up]);
+        assert_eq!(
+            pending_keys.push(Key::Char('j'), key_mapping),
+            vec![Action::Down]
+        );
+        assert_eq!(pending_keys.push(Key::F(12), key_mapping), vec![]);
+
+        assert_eq!(pending_keys.push(Key::Char('g'), key_mapping), vec![]);
+        assert_eq!(
+            pending_keys.push(Key::Char('g'), key_mapping),
+            vec![Action::Top]
+        );
+
+        assert_eq!(pending_keys.push(Key::Char('g'), key_mapping), vec![]);
+        assert_eq!(
+            pending_keys.push(Key::Char('j'), key_mapping),
+            vec![Action::Bottom, Action::Down]
+        );
+
+        assert_eq!(pending_keys.push(Key::Char('g'), key_mapping), vec![]);
+        assert_eq!(pending_keys.expire(key_mapping), None);
+
+        pending_keys.last_key_time -= Duration::from_millis(KEY_SEQUENCE_TIMEOUT);
+        assert_eq!(pending_keys.expire(key_mapping), Some(Action::Bottom));
+        assert_eq!(pending_keys.expire(key_mapping), None);
+    }
+}
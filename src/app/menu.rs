@@ -1,120 +1,81 @@
+use crate::app::event::{Event, EventHandler};
 use crate::app::helper::{Cursor, Screen};
 use crate::app::selection::PrintStyle;
-use std::str::FromStr;
+use crate::app::tabs::{Region, Tab};
+use std::time::{Duration, Instant};
 
 use crate::app::selection::Selection;
 use crate::app::Action;
+use crate::config::Config;
 
-use termion;
-use termion::event::Key;
+const MENU_SPACING: &str = "   ";
+const MENU_HEIGHT: u16 = 1;
+const WRAPPER: &str = " ";
 
-
-const MENU_SPACING: &'static str = "   ";
-const WRAPPER: &'static str = " ";
-
-#[derive(PartialEq, Clone)]
-pub enum MenuItem {
-    Todo,
-    Done,
-    Settings,
-    Help,
-}
-
-impl<'a> MenuItem {
-    fn as_str(&self) -> String {
-        match self {
-            // TODO: probably move this logic to `selection` as `get_wrapped_str`
-            MenuItem::Todo => format!("{WRAPPER}TODO{WRAPPER}"),
-            MenuItem::Done => format!("{WRAPPER}DONE{WRAPPER}"),
-            MenuItem::Settings => format!("{WRAPPER}SETTINGS{WRAPPER}"),
-            MenuItem::Help => format!("{WRAPPER}HELP{WRAPPER}"),
-        }
-    }
-}
-
-impl FromStr for MenuItem {
-    type Err = &'static str;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let trim = s.trim();
-
-        match trim {
-            "TODO" => Ok(MenuItem::Todo),
-            "DONE" => Ok(MenuItem::Done),
-            "SETTINGS" => Ok(MenuItem::Settings),
-            "HELP" => Ok(MenuItem::Help),
-            _ => return Err("No such menu item!"),
-        }
-    }
-}
-
-#[derive(Clone)]
 pub struct Menu<'a> {
-    menu: [MenuItem; 4],
-    pub selected_menu: MenuItem,
-    selection_style: &'a Selection,
-    key_mapping: &'a Vec<(Action, char)>,
+    tabs: Vec<Box<dyn Tab + 'a>>,
+    selected_index: usize,
+    is_hidden: bool,
+    last_key_time: Instant,
 }
 
 impl<'a> Menu<'a> {
-    pub fn init(selection_style: &'a Selection, key_mapping: &'a Vec<(Action, char)>) -> Self {
+    pub fn init(mut tabs: Vec<Box<dyn Tab + 'a>>, config: &Config) -> Self {
+        if let Some(tab) = tabs.first_mut() {
+            tab.on_focus(config);
+        }
+
         Menu {
-            menu: [
-                MenuItem::Todo,
-                MenuItem::Done,
-                MenuItem::Settings,
-                MenuItem::Help,
-            ],
-            selected_menu: MenuItem::Todo,
-            selection_style,
-            key_mapping,
+            tabs,
+            selected_index: 0,
+            is_hidden: false,
+            last_key_time: Instant::now(),
         }
     }
 
-    pub fn set_selected_menu(&mut self, menu_item: MenuItem) {
-        self.selected_menu = menu_item;
+    pub fn get_selected_title(&self) -> Option<String> {
+        self.tabs.get(self.selected_index).map(|tab| tab.title())
     }
 
-    fn get_prev_menu(&self) -> MenuItem {
-        let index = self
-            .menu
-            .iter()
-            .position(|item| *item == self.selected_menu)
-            .expect("No such menu item");
+    pub fn is_hidden(&self) -> bool {
+        self.is_hidden
+    }
 
-        let chosen_menu = if index > 0 {
-            self.menu[index - 1].clone()
-        } else {
-            self.selected_menu.clone()
-        };
+    pub fn hide(&mut self) {
+        self.is_hidden = true;
+        Screen::clear_rows(1, 1);
+    }
 
-        chosen_menu
+    pub fn show(&mut self) {
+        self.is_hidden = false;
     }
 
-    fn get_next_menu(&self) -> MenuItem {
-        let index = self
-            .menu
-            .iter()
-            .position(|item| *item == self.selected_menu)
-            .expect("No such menu item");
+    fn select(&mut self, index: usize, config: &Config) {
+        if index == self.selected_index || index >= self.tabs.len() {
+            return;
+        }
 
-        let chosen_menu = if index < self.menu.len() - 1 {
-            self.menu[index + 1].clone()
-        } else {
-            self.selected_menu.clone()
-        };
+        self.tabs[self.selected_index].on_blur(config);
+        self.selected_index = index;
+        self.tabs[self.selected_index].on_focus(config);
 
-        chosen_menu
+        Screen::clear();
     }
 
-    pub fn render(&self) {
-        Cursor::reset();
+    pub fn render(&self, config: &Config) {
+        if let Some(tab) = self.tabs.get(self.selected_index) {
+            tab.render(Region::below(MENU_HEIGHT), config);
+        }
+
+        if self.is_hidden {
+            return;
+        }
 
-        let menu = self.menu.clone();
+        Cursor::reset();
 
-        for item in menu {
-            let selection = if item == self.selected_menu {
-                Some(self.selection_style)
+        for (index, tab) in self.tabs.iter().enumerate() {
+            let selection = if index == self.selected_index {
+                Some(&config.selection_style)
             } else {
                 None
             };
@@ -123,27 +84,103 @@ impl<'a> Menu<'a> {
                 selection,
                 strikethrough: false,
                 spacing: Some(MENU_SPACING),
+                tags: false,
             };
 
-            Selection::print_styled(&item.as_str(), print_style);
+            Selection::print_styled(&format!("{WRAPPER}{}{WRAPPER}", tab.title()), print_style);
+        }
+    }
+
+    fn listen_action(&mut self, action: &Action, config: &mut Config) {
+        match action {
+            Action::PrevMenu | Action::NextMenu if self.is_hidden => self.show(),
+            Action::PrevMenu => self.select(self.selected_index.saturating_sub(1), config),
+            Action::NextMenu => self.select(self.selected_index + 1, config),
+            _ => {
+                if let Some(tab) = self.tabs.get_mut(self.selected_index) {
+                    tab.handle_action(action, config);
+                }
+            }
         }
     }
+}
 
-    pub fn listen_keys(&mut self, key: &Key) {
-        match key {
-            Key::Char(ch) if ch == &Action::get_action_char(self.key_mapping, Action::PrevMenu) => {
-                let chosen_menu = self.get_prev_menu();
+impl EventHandler for Menu<'_> {
+    fn handle_event(&mut self, event: &Event, config: &mut Config) {
+        match event {
+            Event::Action(action) => {
+                self.last_key_time = Instant::now();
+                self.listen_action(action, config);
 
-                self.set_selected_menu(chosen_menu);
-                Screen::clear();
+                return;
             }
-            Key::Char(ch) if ch == &Action::get_action_char(self.key_mapping, Action::NextMenu) => {
-                let chosen_menu = self.get_next_menu();
+            Event::Tick if !config.auto_hide_menu => self.show(),
+            Event::Tick => {
+                let timeout = Duration::from_millis(config.hide_menu_timeout as u64);
 
-                self.set_selected_menu(chosen_menu);
-                Screen::clear();
+                if !self.is_hidden && self.last_key_time.elapsed() >= timeout {
+                    self.hide();
+                }
             }
-            _ => {}
+            Event::Resize | Event::FileChanged => {}
         }
+
+        for tab in &mut self.tabs {
+            tab.handle_event(event, config);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::tabs::help::Help;
+    use crate::app::tabs::settings::Settings;
+
+    fn init_menu(config: &Config) -> Menu<'static> {
+        Menu::init(
+            vec![Box::new(Settings::init()), Box::new(Help::init())],
+            config,
+        )
+    }
+
+    #[test]
+    fn test_navigation_between_tabs() {
+        let mut config = Config::default();
+        let mut menu = init_menu(&config);
+
+        menu.handle_event(&Event::Action(Action::PrevMenu), &mut config);
+        assert_eq!(menu.get_selected_title().unwrap(), "SETTINGS");
+
+        menu.handle_event(&Event::Action(Action::NextMenu), &mut config);
+        menu.handle_event(&Event::Action(Action::NextMenu), &mut config);
+        assert_eq!(menu.get_selected_title().unwrap(), "HELP");
+    }
+
+    #[test]
+    fn test_hidden_menu_is_shown_on_navigation() {
+        let mut config = Config::default();
+        let mut menu = init_menu(&config);
+
+        config.auto_hide_menu = true;
+        config.hide_menu_timeout = 0;
+
+        menu.handle_event(&Event::Tick, &mut config);
+        assert!(menu.is_hidden());
+
+        menu.handle_event(&Event::Action(Action::Down), &mut config);
+        assert!(menu.is_hidden());
+
+        menu.handle_event(&Event::Action(Action::NextMenu), &mut config);
+        assert!(!menu.is_hidden());
+        assert_eq!(menu.get_selected_title().unwrap(), "SETTINGS");
+
+        menu.handle_event(&Event::Action(Action::NextMenu), &mut config);
+        assert_eq!(menu.get_selected_title().unwrap(), "HELP");
+
+        config.auto_hide_menu = false;
+        menu.hide();
+        menu.handle_event(&Event::Tick, &mut config);
+        assert!(!menu.is_hidden());
     }
 }
@@ -0,0 +1,355 @@
+use crate::app::event::{Event, EventHandler};
+use crate::app::helper::{Cursor, Print, Screen};
+use crate::app::selection::{PrintStyle, Selection};
+use crate::app::tabs::todo::{get_visible_range, parse_date, Status, Todo, TodoItem, DATE_FORMAT};
+use crate::config::{keys, Action, Config};
+use crate::storage;
+
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::path::Path;
+use std::rc::Rc;
+
+use core::str::FromStr;
+
+use chrono::{Local, NaiveDate};
+
+use rustyline::Editor;
+
+const TITLE: &str = "Unfinished from previous days";
+const LIST_LEFT_MARGIN: &str = "  ";
+const LIST_TOP_MARGIN: u16 = 3;
+const LIST_SPACING: &str = " ";
+const RESCHEDULE_PROMPT: &str = "reschedule: ";
+pub const DECISION_SEPARATOR: &str = ",";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Decision {
+    CarryOver,
+    Reschedule(NaiveDate),
+    Drop,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecisionRecord {
+    pub date: NaiveDate,
+    pub decision: Decision,
+}
+
+impl fmt::Display for DecisionRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let date = self.date.format(DATE_FORMAT);
+
+        match self.decision {
+            Decision::CarryOver => write!(f, "{date} carry"),
+            Decision::Reschedule(to) => write!(f, "{date} reschedule {}", to.format(DATE_FORMAT)),
+            Decision::Drop => write!(f, "{date} drop"),
+        }
+    }
+}
+
+impl FromStr for DecisionRecord {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_date =
+            |value: &str| NaiveDate::parse_from_str(value, DATE_FORMAT).map_err(|_| ());
+        let words: Vec<&str> = s.split_whitespace().collect();
+
+        let decision = match words[..] {
+            [_, "carry"] => Decision::CarryOver,
+            [_, "reschedule", to] => Decision::Reschedule(parse_date(to)?),
+            [_, "drop"] => Decision::Drop,
+            _ => return Err(()),
+        };
+
+        Ok(DecisionRecord {
+            date: parse_date(words[0])?,
+            decision,
+        })
+    }
+}
+
+fn get_unfinished(todo_list: &[TodoItem], today: NaiveDate) -> Vec<u16> {
+    todo_list
+        .iter()
+        .filter(|item| item.status == Status::Todo)
+        .filter(|item| item.date_created.with_timezone(&Local).date_naive() < today)
+        .filter(|item| item.scheduled.is_none_or(|date| date < today))
+        .map(|item| item.id())
+        .collect()
+}
+
+pub struct Reminder<'a> {
+    todo: Rc<RefCell<Todo<'a>>>,
+    path: &'a Path,
+    ids: Vec<u16>,
+    selected_index: u16,
+    scroll_offset: Cell<usize>,
+    error: Option<String>,
+}
+
+impl<'a> Reminder<'a> {
+    // --- Note ---
+    // Reminder is shown until every todo on it is decided, the day is written down only then. If
+    // it can't be written down the reminder is simply shown again on the next start
+    pub fn init(todo: Rc<RefCell<Todo<'a>>>, path: &'a Path) -> Option<Self> {
+        let today = Local::now().date_naive();
+
+        if storage::read_reminded_date(path) == Some(today) {
+            return None;
+        }
+
+        let ids = get_unfinished(&todo.borrow().todo_list, today);
+
+        if ids.is_empty() {
+            storage::write_reminded_date(path, today).ok();
+
+            return None;
+        }
+
+        Some(Reminder {
+            todo,
+            path,
+            ids,
+            selected_index: 0,
+            scroll_offset: Cell::new(0),
+            error: None,
+        })
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    fn get_view<'b>(&self, todo_list: &'b [TodoItem]) -> Vec<&'b TodoItem> {
+        self.ids
+            .iter()
+            .filter_map(|id| todo_list.iter().find(|item| item.id() == *id))
+            .collect()
+    }
+
+    pub fn render(&self, config: &Config) {
+        let today = Local::now().date_naive();
+        let todo = self.todo.borrow();
+        let view = self.get_view(&todo.todo_list);
+        let (_, y_size) = Screen::get_size();
+        let heights: Vec<u16> = view
+            .iter()
+            .map(|item| TodoItem::get_line_height(&item.format(today, None, None)))
+            .collect();
+        let (start, end) = get_visible_range(
+            &heights,
+            self.scroll_offset.get(),
+            self.selected_index as usize,
+            y_size.saturating_sub(LIST_TOP_MARGIN + 1),
+        );
+
+        if start != self.scroll_offset.get() {
+            self.scroll_offset.set(start);
+            Screen::clear();
+        }
+
+        Cursor::place(LIST_LEFT_MARGIN.len() as u16, 1);
+        print!(
+            "{bold}{TITLE}{reset}",
+            bold = termion::style::Bold,
+            reset = termion::style::Reset,
+        );
+
+        let mut cursor_y = LIST_TOP_MARGIN;
+
+        for (index, item) in view.iter().enumerate().take(end).skip(start) {
+            let selection = if index == self.selected_index as usize {
+                Some(&config.selection_style)
+            } else {
+                None
+            };
+
+            Cursor::place(LIST_LEFT_MARGIN.len() as u16, cursor_y);
+            Selection::print_styled(
+                &item.format(today, Some(config), None),
+                PrintStyle {
+                    selection,
+                    strikethrough: false,
+                    spacing: Some(LIST_SPACING),
+                    tags: true,
+                },
+            );
+
+            cursor_y += heights[index];
+        }
+
+        if start > 0 || end < view.len() {
+            Print::scroll_indicator(start, end, view.len());
+        }
+
+        let key_mapping = &config.key_mapping;
+        let status = self.error.clone().unwrap_or_else(|| {
+            format!(
+                "{carry}: carry over · {reschedule}: reschedule · {drop}: drop",
+                carry = keys::format_bindings(Action::get_bindings(key_mapping, &Action::Mark)),
+                reschedule =
+                    keys::format_bindings(Action::get_bindings(key_mapping, &Action::EditTodo)),
+                drop =
+                    keys::format_bindings(Action::get_bindings(key_mapping, &Action::RemoveTodo)),
+            )
+        });
+
+        Print::status(&status);
+        Print::finsih();
+    }
+
+    fn read_date() -> Option<String> {
+        let (_, y_size) = Screen::get_size();
+
+        Cursor::show();
+        Cursor::place(1, y_size.saturating_sub(1));
+
+        let mut rl = Editor::<()>::new().unwrap();
+        let line = rl.readline(RESCHEDULE_PROMPT).ok();
+
+        Cursor::hide();
+        Screen::clear();
+
+        line
+    }
+
+    fn take_selected(&mut self) -> Option<u16> {
+        if self.ids.is_empty() {
+            return None;
+        }
+
+        let id = self.ids.remove(self.selected_index as usize);
+        let last_index = self.ids.len().saturating_sub(1) as u16;
+
+        self.selected_index = self.selected_index.min(last_index);
+        Screen::clear();
+
+        Some(id)
+    }
+
+    fn listen_action(&mut self, action: &Action) -> Option<(u16, Decision)> {
+        let last_index = self.ids.len().saturating_sub(1) as u16;
+
+        if self.error.take().is_some() {
+            Screen::clear();
+        }
+
+        match action {
+            Action::Up => {
+                self.selected_index = self.selected_index.saturating_sub(1);
+            }
+            Action::Down => {
+                self.selected_index = (self.selected_index + 1).min(last_index);
+            }
+            Action::Mark => {
+                return self.take_selected().map(|id| (id, Decision::CarryOver));
+            }
+            Action::EditTodo => {
+                let input = Reminder::read_date()?;
+
+                if input.trim().is_empty() {
+                    return None;
+                }
+
+                match parse_date(input.trim(), Local::now().date_naive()) {
+                    Some(date) => {
+                        return self
+                            .take_selected()
+                            .map(|id| (id, Decision::Reschedule(date)));
+                    }
+                    None => self.error = Some(format!("'{}' is not a valid date", input.trim())),
+                }
+            }
+            Action::RemoveTodo => {
+                return self.take_selected().map(|id| (id, Decision::Drop));
+            }
+            _ => {}
+        }
+
+        None
+    }
+}
+
+impl EventHandler for Reminder<'_> {
+    fn handle_event(&mut self, event: &Event, _config: &mut Config) {
+        if let Event::Action(action) = event {
+            if let Some((id, decision)) = self.listen_action(action) {
+                self.todo.borrow_mut().apply_decision(id, &decision);
+
+                if self.is_finished() {
+                    storage::write_reminded_date(self.path, Local::now().date_naive()).ok();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{MemoryStore, TodoStore};
+
+    use chrono::{Duration, Utc};
+
+    #[test]
+    fn test_unfinished_items() {
+        let today = Local::now().date_naive();
+        let mut todo_list: Vec<TodoItem> = (0..5)
+            .map(|id| TodoItem::new(id, &format!("Todo {id}")))
+            .collect();
+
+        for item in todo_list.iter_mut().take(4) {
+            item.date_created = Utc::now() - Duration::days(2);
+        }
+
+        todo_list[1].set_status(Status::Done);
+        todo_list[2].scheduled = Some(today + Duration::days(1));
+        todo_list[3].scheduled = Some(today - Duration::days(1));
+
+        assert_eq!(get_unfinished(&todo_list, today), vec![0, 3]);
+    }
+
+    #[test]
+    fn test_drop_closes_todo() {
+        let today = Local::now().date_naive();
+        let store = MemoryStore::new(vec![TodoItem::new(0, "Walk"), TodoItem::new(1, "Pay rent")]);
+        let mut todo = Todo::init(store.clone()).unwrap();
+
+        todo.apply_decision(0, &Decision::Drop);
+
+        let todo_list = store.load().unwrap().0;
+
+        assert_eq!(todo_list.len(), 2);
+        assert_eq!(todo_list[0].status, Status::Done);
+        assert_eq!(
+            todo_list[0].decisions,
+            vec![DecisionRecord {
+                date: today,
+                decision: Decision::Drop,
+            }]
+        );
+        assert_eq!(todo_list[1].status, Status::Todo);
+        assert!(todo_list[1].decisions.is_empty());
+        assert!(!get_unfinished(&todo_list, today + Duration::days(1)).contains(&0));
+    }
+
+    #[test]
+    fn test_decision_record() {
+        for record in [
+            "2026-10-18 carry",
+            "2026-10-18 reschedule 2026-10-25",
+            "2026-10-18 drop",
+        ] {
+            assert_eq!(
+                DecisionRecord::from_str(record).unwrap().to_string(),
+                record
+            );
+        }
+
+        assert!(DecisionRecord::from_str("2026-10-18 postpone").is_err());
+        assert!(DecisionRecord::from_str("yesterday drop").is_err());
+        assert!(DecisionRecord::from_str("").is_err());
+    }
+}
@@ -1,14 +1,17 @@
 use core::str::FromStr;
+use std::slice::Iter;
 
 use crate::app::helper::Print;
+use crate::app::tabs::todo::Tag;
 
-const WRAPPER: &'static str = " ";
-const DEFAULT_SPACING: &'static str = " ";
+const WRAPPER: &str = " ";
+const DEFAULT_SPACING: &str = " ";
 
 pub struct PrintStyle<'a> {
     pub selection: Option<&'a Selection>,
     pub strikethrough: bool,
     pub spacing: Option<&'a str>,
+    pub tags: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -20,6 +23,26 @@ pub enum Selection {
 }
 
 impl Selection {
+    pub fn iterate() -> Iter<'static, Selection> {
+        static SELECTIONS: [Selection; 4] = [
+            Selection::Brackets,
+            Selection::Tilde,
+            Selection::Outline,
+            Selection::Bold,
+        ];
+        SELECTIONS.iter()
+    }
+
+    pub fn next(&self) -> Self {
+        let styles: Vec<&Selection> = Selection::iterate().collect();
+        let index = styles
+            .iter()
+            .position(|style| *style == self)
+            .expect("No such selection style");
+
+        styles[(index + 1) % styles.len()].clone()
+    }
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Selection::Brackets => "brackets",
@@ -53,8 +76,15 @@ impl Selection {
             selection,
             strikethrough,
             spacing,
+            tags,
         } = style;
 
+        let string = if tags {
+            Tag::highlight(string)
+        } else {
+            string.to_owned()
+        };
+
         let text = if strikethrough {
             let last_index = string.len() - 1;
             // TODO: can be improved in the future for non-ASCII chars
@@ -69,7 +99,7 @@ impl Selection {
                 last_char = &string[last_index..],
             )
         } else {
-            string.to_owned()
+            string
         };
 
         match selection {
@@ -83,6 +113,14 @@ impl Selection {
                 );
             }
             Some(Selection::Outline) => {
+                // --- Note ---
+                // Colored parts of the text reset the color to the terminal default, which might
+                // be unreadable on the outline background
+                let text = text.replace(
+                    &termion::color::Fg(termion::color::Reset).to_string(),
+                    &termion::color::Fg(termion::color::Black).to_string(),
+                );
+
                 print!(
                     "{bg}{fg}{item}{bg_clear}{fg_clear}{spacing}",
                     bg = termion::color::Bg(termion::color::White),
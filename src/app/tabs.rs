@@ -2,3 +2,78 @@ pub mod done;
 pub mod help;
 pub mod settings;
 pub mod todo;
+
+use crate::app::event::EventHandler;
+use crate::app::helper::Screen;
+use crate::config::{Action, Config};
+use done::Done;
+use help::Help;
+use settings::Settings;
+use todo::Todo;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Region {
+    pub top: u16,
+    pub bottom: u16,
+}
+
+impl Region {
+    pub fn below(rows: u16) -> Self {
+        let (_, y_size) = Screen::get_size();
+
+        Region {
+            top: rows + 1,
+            bottom: y_size,
+        }
+    }
+}
+
+impl Default for Region {
+    fn default() -> Self {
+        Region { top: 1, bottom: 1 }
+    }
+}
+
+pub trait Tab: EventHandler {
+    fn title(&self) -> String;
+    fn render(&self, region: Region, config: &Config);
+    fn handle_action(&mut self, action: &Action, config: &mut Config);
+    fn on_focus(&mut self, _config: &Config) {}
+    fn on_blur(&mut self, _config: &Config) {}
+}
+
+// --- Note ---
+// Tabs in the order of the menu, the todo list is shared with the tabs which show or change it
+pub fn init<'a>(todo: &Rc<RefCell<Todo<'a>>>) -> Vec<Box<dyn Tab + 'a>> {
+    vec![
+        Box::new(Rc::clone(todo)),
+        Box::new(Done::init(Rc::clone(todo))),
+        Box::new(Settings::init()),
+        Box::new(Help::init()),
+    ]
+}
+
+impl<T: Tab + ?Sized> Tab for Rc<RefCell<T>> {
+    fn title(&self) -> String {
+        self.borrow().title()
+    }
+
+    fn render(&self, region: Region, config: &Config) {
+        self.borrow().render(region, config);
+    }
+
+    fn handle_action(&mut self, action: &Action, config: &mut Config) {
+        self.borrow_mut().handle_action(action, config);
+    }
+
+    fn on_focus(&mut self, config: &Config) {
+        self.borrow_mut().on_focus(config);
+    }
+
+    fn on_blur(&mut self, config: &Config) {
+        self.borrow_mut().on_blur(config);
+    }
+}
@@ -1,61 +1,391 @@
-use crate::app::helper::{Print, Cursor};
+use crate::app::event::{Event, EventHandler};
+use crate::app::helper::{Cursor, Print, Screen};
+use crate::app::selection::{PrintStyle, Selection};
+use crate::config::{Action, Config};
 
-use super::todo::{Status, TodoItem};
+use super::todo::{get_visible_range, Status, Todo, TodoItem, DAY_FORMAT};
+use super::{Region, Tab};
 
-use chrono::Utc;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
 
-pub struct Done {
+use chrono::{Days, Local, NaiveDate};
+
+const LIST_LEFT_MARGIN: &str = "  ";
+const TITLE: &str = "DONE";
+const LIST_SPACING: &str = " ";
+
+#[derive(Debug, PartialEq)]
+enum Row {
+    Day(NaiveDate),
+    Item(usize),
+}
+
+fn get_completion_day(item: &TodoItem) -> NaiveDate {
+    item.get_date_completed()
+        .unwrap_or(item.date_modified)
+        .with_timezone(&Local)
+        .date_naive()
+}
+
+fn format_day(day: NaiveDate, today: NaiveDate) -> String {
+    if day == today {
+        String::from("Today")
+    } else if Some(day) == today.checked_sub_days(Days::new(1)) {
+        String::from("Yesterday")
+    } else {
+        day.format(DAY_FORMAT).to_string()
+    }
+}
+
+fn get_rows(view: &[&TodoItem]) -> Vec<Row> {
+    let mut rows = Vec::new();
+    let mut last_day = None;
+
+    for (index, item) in view.iter().enumerate() {
+        let day = get_completion_day(item);
+
+        if last_day != Some(day) {
+            rows.push(Row::Day(day));
+            last_day = Some(day);
+        }
+
+        rows.push(Row::Item(index));
+    }
+
+    rows
+}
+
+pub struct Done<'a> {
+    todo: Rc<RefCell<Todo<'a>>>,
     done_list: Vec<TodoItem>,
+    selected_index: u16,
+    scroll_offset: Cell<usize>,
+    region: Cell<Region>,
 }
 
-impl Done {
-    pub fn init(todo_list: &Vec<TodoItem>) -> Self {
-        let done_list = todo_list
-            .clone()
-            .into_iter()
-            .filter(|item| {
-                let today = Utc::now().date();
-                let modified = item.date_modified.date();
+impl<'a> Done<'a> {
+    pub fn init(todo: Rc<RefCell<Todo<'a>>>) -> Self {
+        let mut done = Self {
+            todo,
+            done_list: Vec::new(),
+            selected_index: 0,
+            scroll_offset: Cell::new(0),
+            region: Cell::new(Region::default()),
+        };
 
-                item.status == Status::Done && today > modified
-            })
+        done.refresh();
+
+        done
+    }
+
+    pub fn refresh(&mut self) {
+        let selected_id = self.get_selected().map(|item| item.id());
+
+        self.done_list = self
+            .todo
+            .borrow()
+            .todo_list
+            .iter()
+            .filter(|item| item.status == Status::Done)
+            .cloned()
             .collect();
+        self.done_list
+            .sort_by_key(|item| std::cmp::Reverse(item.get_date_completed()));
+
+        let view = self.get_view();
+        let last_index = view.len().saturating_sub(1) as u16;
+        let index = selected_id.and_then(|id| view.iter().position(|item| item.id() == id));
+
+        if let Some(index) = index {
+            self.selected_index = index as u16;
+        }
+
+        self.selected_index = self.selected_index.min(last_index);
+    }
+
+    fn get_view(&self) -> Vec<&TodoItem> {
+        let todo = self.todo.borrow();
+        let filter = todo.get_filter();
+
+        self.done_list
+            .iter()
+            .filter(|item| filter.matches(item))
+            .collect()
+    }
+
+    fn prompt_filter(&mut self) {
+        let filter = {
+            let todo = self.todo.borrow();
+
+            todo.get_filter().prompt(&todo.todo_list)
+        };
+
+        if let Some(filter) = filter {
+            self.todo.borrow_mut().set_filter(filter);
+            self.selected_index = 0;
+        }
+    }
+
+    fn get_selected(&self) -> Option<&TodoItem> {
+        self.get_view().get(self.selected_index as usize).copied()
+    }
+
+    fn get_heights(rows: &[Row], view: &[&TodoItem], today: NaiveDate) -> Vec<u16> {
+        rows.iter()
+            .enumerate()
+            .map(|(index, row)| match row {
+                Row::Day(_) if index > 0 => 2,
+                Row::Day(_) => 1,
+                Row::Item(item) => {
+                    TodoItem::get_line_height(&view[*item].format(today, None, None))
+                }
+            })
+            .collect()
+    }
+
+    fn get_visible_range(&self, rows: &[Row], heights: &[u16]) -> (usize, usize) {
+        let region = self.region.get();
+        let available = region.bottom.saturating_sub(region.top + 1);
+        let selected = rows
+            .iter()
+            .position(|row| *row == Row::Item(self.selected_index as usize))
+            .unwrap_or(0);
+        let offset = self.scroll_offset.get();
+
+        match selected.checked_sub(1) {
+            Some(header) if matches!(rows[header], Row::Day(_)) && header < offset => {
+                get_visible_range(heights, header, selected, available)
+            }
+            _ => get_visible_range(heights, offset, selected, available),
+        }
+    }
+
+    fn get_page_size(&self) -> u16 {
+        let today = Local::now().date_naive();
+        let view = self.get_view();
+        let rows = get_rows(&view);
+        let (start, end) = self.get_visible_range(&rows, &Done::get_heights(&rows, &view, today));
 
-        Self { done_list }
+        rows[start..end]
+            .iter()
+            .filter(|row| matches!(row, Row::Item(_)))
+            .count()
+            .max(1) as u16
     }
 
-    pub fn render(&self) {
-        Cursor::place(5, 2);
+    fn listen_action(&mut self, action: &Action) -> Option<u16> {
+        let last_index = self.get_view().len().saturating_sub(1) as u16;
 
-        for item in &self.done_list {
-            println!("{}", item.description);
+        match action {
+            Action::Up => {
+                self.selected_index = self.selected_index.saturating_sub(1);
+            }
+            Action::Down => {
+                self.selected_index = (self.selected_index + 1).min(last_index);
+            }
+            Action::PageUp => {
+                self.selected_index = self.selected_index.saturating_sub(self.get_page_size());
+            }
+            Action::PageDown => {
+                self.selected_index = (self.selected_index + self.get_page_size()).min(last_index);
+            }
+            Action::Top => {
+                self.selected_index = 0;
+            }
+            Action::Bottom => {
+                self.selected_index = last_index;
+            }
+            Action::Filter => {
+                self.prompt_filter();
+            }
+            Action::Mark => {
+                let id = self.get_selected().map(|item| item.id());
+
+                Screen::clear();
+
+                return id;
+            }
+            _ => {}
+        }
+
+        None
+    }
+}
+
+impl EventHandler for Done<'_> {
+    fn handle_event(&mut self, event: &Event, _config: &mut Config) {
+        if *event == Event::FileChanged {
+            self.refresh();
+        }
+    }
+}
+
+impl Tab for Done<'_> {
+    fn title(&self) -> String {
+        TITLE.to_owned()
+    }
+
+    fn render(&self, region: Region, config: &Config) {
+        self.region.set(region);
+
+        let today = Local::now().date_naive();
+        let view = self.get_view();
+        let rows = get_rows(&view);
+        let heights = Done::get_heights(&rows, &view, today);
+        let (start, end) = self.get_visible_range(&rows, &heights);
+
+        if start != self.scroll_offset.get() {
+            self.scroll_offset.set(start);
+            Screen::clear();
+        }
+
+        let mut cursor_y = region.top;
+
+        for (row, height) in rows.iter().zip(&heights).take(end).skip(start) {
+            match row {
+                Row::Day(day) => {
+                    Cursor::place(LIST_LEFT_MARGIN.len() as u16, cursor_y + height - 1);
+                    print!(
+                        "{bold}{day}{reset}",
+                        bold = termion::style::Bold,
+                        day = format_day(*day, today),
+                        reset = termion::style::Reset,
+                    );
+                }
+                Row::Item(index) => {
+                    let item = view[*index];
+                    let selection = if *index == self.selected_index as usize {
+                        Some(&config.selection_style)
+                    } else {
+                        None
+                    };
+
+                    Cursor::place(LIST_LEFT_MARGIN.len() as u16, cursor_y);
+                    Selection::print_styled(
+                        &item.format(today, Some(config), None),
+                        PrintStyle {
+                            selection,
+                            strikethrough: false,
+                            spacing: Some(LIST_SPACING),
+                            tags: true,
+                        },
+                    );
+                }
+            }
+
+            cursor_y += height;
+        }
+
+        if start > 0 || end < rows.len() {
+            let items = |rows: &[Row]| {
+                rows.iter()
+                    .filter(|row| matches!(row, Row::Item(_)))
+                    .count()
+            };
+
+            Print::scroll_indicator(items(&rows[..start]), items(&rows[..end]), view.len());
+        }
+
+        let todo = self.todo.borrow();
+
+        if !todo.get_filter().is_empty() {
+            Print::status(&format!("filter: {}", todo.get_filter()));
         }
 
         Print::finsih();
     }
+
+    fn handle_action(&mut self, action: &Action, _config: &mut Config) {
+        if let Some(id) = self.listen_action(action) {
+            self.todo.borrow_mut().unmark(id);
+            self.refresh();
+        }
+    }
+
+    fn on_focus(&mut self, _config: &Config) {
+        self.refresh();
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use chrono::{DateTime, Utc};
+    use super::*;
+    use crate::storage::MemoryStore;
+
+    use chrono::{DateTime, Duration, Utc};
 
     #[test]
-    fn check_dates() {
-        let first_date_str = "2023-01-01 15:31:01.585089387 UTC";
-        let second_date_str = "2022-10-20 15:32:01.585089387 UTC";
+    fn test_rows_are_grouped_by_day() {
+        let now = Utc::now();
+        let mut todo_list: Vec<TodoItem> = (0..4)
+            .map(|id| TodoItem::new(id, &format!("Todo {id}")))
+            .collect();
 
-        let first_date = first_date_str.parse::<DateTime<Utc>>().unwrap().date();
-        let second_date = second_date_str.parse::<DateTime<Utc>>().unwrap().date();
+        for (item, days) in todo_list.iter_mut().zip([0, 2, 0, 1]) {
+            item.status = Status::Done;
+            item.date_completed = Some(now - Duration::days(days));
+        }
+
+        todo_list[3].status = Status::Todo;
 
-        let today = Utc::now().date();
+        let done = Done::init(Rc::new(RefCell::new(
+            Todo::init(MemoryStore::new(todo_list.clone())).unwrap(),
+        )));
+        let view = done.get_view();
+        let ids: Vec<u16> = view.iter().map(|item| item.id()).collect();
 
-        println!(
-            "FIRST DATE : {}, SECOND DATE: {}, FIRST IS MORE THAN SECOND: {}",
-            first_date,
-            second_date,
-            first_date > second_date
+        assert_eq!(ids, vec![0, 2, 1]);
+        assert_eq!(
+            get_rows(&view),
+            vec![
+                Row::Day(get_completion_day(&todo_list[0])),
+                Row::Item(0),
+                Row::Item(1),
+                Row::Day(get_completion_day(&todo_list[1])),
+                Row::Item(2),
+            ]
         );
+    }
 
-        // assert!(today < yesterday);
+    #[test]
+    fn test_format_day() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+
+        assert_eq!(format_day(today, today), "Today");
+        assert_eq!(
+            format_day(NaiveDate::from_ymd_opt(2026, 10, 17).unwrap(), today),
+            "Yesterday"
+        );
+        assert_eq!(
+            format_day(NaiveDate::from_ymd_opt(2026, 10, 16).unwrap(), today),
+            "Friday, 16 October 2026"
+        );
+        assert_eq!(
+            format_day(NaiveDate::from_ymd_opt(2026, 10, 19).unwrap(), today),
+            "Monday, 19 October 2026"
+        );
+    }
+
+    #[test]
+    fn test_completion_day() {
+        let modified = "2026-10-10T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let completed = "2026-10-12T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let mut item = TodoItem::new(0, "Walk");
+
+        item.status = Status::Done;
+        item.date_modified = modified;
+
+        assert_eq!(
+            get_completion_day(&item),
+            modified.with_timezone(&Local).date_naive()
+        );
+
+        item.date_completed = Some(completed);
+
+        assert_eq!(
+            get_completion_day(&item),
+            completed.with_timezone(&Local).date_naive()
+        );
     }
 }
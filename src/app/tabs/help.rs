@@ -0,0 +1,190 @@
+use super::{Region, Tab};
+use crate::app::event::EventHandler;
+use crate::app::helper::{Cursor, Print, Screen};
+use crate::app::tabs::todo::get_visible_range;
+use crate::config::keys::{self, Binding};
+use crate::config::{Action, Config};
+
+use std::cell::Cell;
+
+const COLUMN_SPACING: &str = "   ";
+const LIST_LEFT_MARGIN: &str = "  ";
+const TITLE: &str = "HELP";
+const HEADER: [&str; 4] = ["KEY", "ACTION", "DESCRIPTION", "TAB"];
+const HEADER_HEIGHT: u16 = 1;
+
+fn describe(action: &Action) -> (&'static str, &'static str) {
+    match action {
+        Action::Up => (
+            "Move selection up / scroll up",
+            "Todo, Done, Settings, Help, Reminder",
+        ),
+        Action::Down => (
+            "Move selection down / scroll down",
+            "Todo, Done, Settings, Help, Reminder",
+        ),
+        Action::PrevMenu => ("Switch to the previous tab", "All"),
+        Action::NextMenu => ("Switch to the next tab", "All"),
+        Action::Mark => (
+            "Mark todo as done / move it back to todo / change setting / carry todo over",
+            "Todo, Done, Settings, Reminder",
+        ),
+        Action::Quit => ("Quit mindr", "All"),
+        Action::AddTodo => ("Add a new todo", "Todo"),
+        Action::RemoveTodo => (
+            "Remove selected todo / stop reminding about todo",
+            "Todo, Reminder",
+        ),
+        Action::EditTodo => (
+            "Edit selected todo, empty text removes it / reschedule todo",
+            "Todo, Reminder",
+        ),
+        Action::Undo => ("Undo the last change of the todo list", "Todo"),
+        Action::Redo => ("Redo the last undone change", "Todo"),
+        Action::MoveUp => ("Move selected todo up", "Todo"),
+        Action::MoveDown => ("Move selected todo down", "Todo"),
+        Action::PageUp => ("Move selection one page up", "Todo, Done"),
+        Action::PageDown => ("Move selection one page down", "Todo, Done"),
+        Action::Top => ("Move selection to the first todo", "Todo, Done"),
+        Action::Bottom => ("Move selection to the last todo", "Todo, Done"),
+        Action::Sort => ("Sort todos by manual order, due or scheduled date", "Todo"),
+        Action::RaisePriority => ("Raise priority of selected todo", "Todo"),
+        Action::LowerPriority => (
+            "Lower priority of selected todo, below E removes it",
+            "Todo",
+        ),
+        Action::Filter => (
+            "Filter todos by +project, @context or tag name",
+            "Todo, Done",
+        ),
+        Action::Search => (
+            "Search todos, \\c ignores case, \\C matches it, \\v uses regex",
+            "Todo",
+        ),
+        Action::NextMatch => ("Select the next search match", "Todo"),
+        Action::PrevMatch => ("Select the previous search match", "Todo"),
+    }
+}
+
+fn get_rows(key_mapping: &[(Action, Vec<Binding>)]) -> Vec<[String; 4]> {
+    Action::iterate()
+        .map(|action| {
+            let (description, tab) = describe(action);
+            let bindings = Action::get_bindings(key_mapping, action);
+
+            [
+                keys::format_bindings(bindings),
+                action.as_str().to_owned(),
+                description.to_owned(),
+                tab.to_owned(),
+            ]
+        })
+        .collect()
+}
+
+pub struct Help {
+    scroll_offset: Cell<usize>,
+}
+
+impl Help {
+    pub fn init() -> Self {
+        Help {
+            scroll_offset: Cell::new(0),
+        }
+    }
+}
+
+impl Tab for Help {
+    fn title(&self) -> String {
+        TITLE.to_owned()
+    }
+
+    // --- Note ---
+    // Header stays on top, the rows below it scroll. Rows are cut to the screen width, wrapped
+    // ones would push the rest of the table down
+    fn render(&self, region: Region, config: &Config) {
+        let (x_size, _) = Screen::get_size();
+        let header = HEADER.map(String::from);
+        let rows = get_rows(&config.key_mapping);
+        let available = region.bottom.saturating_sub(region.top + HEADER_HEIGHT + 1);
+        let offset = self
+            .scroll_offset
+            .get()
+            .min(rows.len().saturating_sub(available as usize));
+        let (start, end) = get_visible_range(&vec![1; rows.len()], offset, offset, available);
+
+        self.scroll_offset.set(start);
+
+        let mut widths = [0; 4];
+
+        for row in [&header].into_iter().chain(&rows) {
+            for (column, cell) in row.iter().enumerate() {
+                widths[column] = widths[column].max(cell.chars().count());
+            }
+        }
+
+        let visible = rows.iter().take(end).skip(start);
+
+        for (cursor_y, row) in (region.top..).zip([&header].into_iter().chain(visible)) {
+            Cursor::place(LIST_LEFT_MARGIN.len() as u16, cursor_y);
+
+            let line = row
+                .iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<String>>()
+                .join(COLUMN_SPACING);
+            let line: String = line
+                .chars()
+                .take((x_size as usize).saturating_sub(LIST_LEFT_MARGIN.len()))
+                .collect();
+
+            Print::item(line.trim_end(), "");
+        }
+
+        if start > 0 || end < rows.len() {
+            Print::scroll_indicator(start, end, rows.len());
+        }
+
+        Print::finsih();
+    }
+
+    fn handle_action(&mut self, action: &Action, _config: &mut Config) {
+        let offset = self.scroll_offset.get();
+
+        match action {
+            Action::Up => self.scroll_offset.set(offset.saturating_sub(1)),
+            Action::Down => self.scroll_offset.set(offset + 1),
+            _ => return,
+        }
+
+        Screen::clear();
+    }
+}
+
+impl EventHandler for Help {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rows_follow_key_mapping() {
+        let mut config = Config::default();
+
+        for (action, bindings) in config.key_mapping.iter_mut() {
+            if *action == Action::AddTodo {
+                *bindings = keys::parse_bindings("n <C-n>").unwrap();
+            }
+        }
+
+        let rows = get_rows(&config.key_mapping);
+        let add_row = rows
+            .iter()
+            .find(|row| row[1] == Action::AddTodo.as_str())
+            .unwrap();
+
+        assert_eq!(rows.len(), Action::iterate().len());
+        assert_eq!(add_row[0], "n <C-n>");
+    }
+}
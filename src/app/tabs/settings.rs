@@ -0,0 +1,376 @@
+use super::{Region, Tab};
+use crate::app::event::EventHandler;
+use crate::app::helper::{Cursor, Print, Screen};
+use crate::app::selection::{PrintStyle, Selection};
+use crate::app::tabs::todo::get_visible_range;
+use crate::config::{keys, Action, Config};
+
+use std::cell::Cell;
+
+use rustyline::Editor;
+
+const WRAPPER: &str = " ";
+const LIST_SPACING: &str = " ";
+const LIST_LEFT_MARGIN: &str = "  ";
+const TITLE: &str = "SETTINGS";
+const NAME_WIDTH: usize = 20;
+const MAX_HIDE_MENU_TIMEOUT: u16 = 60000;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Setting {
+    DisplayTodays,
+    RemindUnfinished,
+    AutoHideMenu,
+    HideMenuTimeout,
+    SelectionStyle,
+    PriorityColors,
+    StorageBackend,
+    KeyMapping(Action),
+}
+
+impl Setting {
+    fn iterate() -> Vec<Setting> {
+        let mut settings = vec![
+            Setting::DisplayTodays,
+            Setting::RemindUnfinished,
+            Setting::AutoHideMenu,
+            Setting::HideMenuTimeout,
+            Setting::SelectionStyle,
+            Setting::PriorityColors,
+            Setting::StorageBackend,
+        ];
+
+        for action in Action::iterate() {
+            settings.push(Setting::KeyMapping(action.clone()));
+        }
+
+        settings
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Setting::DisplayTodays => "display_todays",
+            Setting::RemindUnfinished => "remind_unfinished",
+            Setting::AutoHideMenu => "auto_hide_menu",
+            Setting::HideMenuTimeout => "hide_menu_timeout",
+            Setting::SelectionStyle => "selection_style",
+            Setting::PriorityColors => "priority_colors",
+            Setting::StorageBackend => "storage_backend",
+            Setting::KeyMapping(action) => action.as_str(),
+        }
+    }
+
+    fn value(&self, config: &Config) -> String {
+        match self {
+            Setting::DisplayTodays => config.display_todays.to_string(),
+            Setting::RemindUnfinished => config.remind_unfinished.to_string(),
+            Setting::AutoHideMenu => config.auto_hide_menu.to_string(),
+            Setting::HideMenuTimeout => config.hide_menu_timeout.to_string(),
+            Setting::SelectionStyle => config.selection_style.as_str().to_owned(),
+            Setting::PriorityColors => config.priority_colors.to_string(),
+            Setting::StorageBackend => config.storage_backend.as_str().to_owned(),
+            Setting::KeyMapping(action) => {
+                keys::format_bindings(Action::get_bindings(&config.key_mapping, action))
+            }
+        }
+    }
+
+    fn format(&self, config: &Config) -> String {
+        format!(
+            "{WRAPPER}{name:<width$}{value}{WRAPPER}",
+            name = self.name(),
+            width = NAME_WIDTH,
+            value = self.value(config)
+        )
+    }
+
+    fn apply(&self, config: &mut Config, input: Option<&str>) -> Result<(), String> {
+        match self {
+            Setting::DisplayTodays => config.display_todays = !config.display_todays,
+            Setting::RemindUnfinished => config.remind_unfinished = !config.remind_unfinished,
+            Setting::AutoHideMenu => config.auto_hide_menu = !config.auto_hide_menu,
+            Setting::SelectionStyle => config.selection_style = config.selection_style.next(),
+            Setting::PriorityColors => config.priority_colors = !config.priority_colors,
+            Setting::StorageBackend => {
+                let backend = config.storage_backend.next();
+
+                if !backend.is_available() {
+                    return Err(format!(
+                        "Storage backend '{}' is not availabe in this build",
+                        backend.as_str()
+                    ));
+                }
+
+                config.storage_backend = backend;
+            }
+            Setting::HideMenuTimeout => {
+                let input = input.unwrap_or_default().trim();
+                let timeout = input
+                    .parse::<u16>()
+                    .map_err(|_| format!("'{input}' is not a valid timeout"))?;
+
+                if timeout > MAX_HIDE_MENU_TIMEOUT {
+                    return Err(format!(
+                        "The value of 'hide_menu_timeout' can not be greater than {MAX_HIDE_MENU_TIMEOUT}"
+                    ));
+                }
+
+                config.hide_menu_timeout = timeout;
+            }
+            Setting::KeyMapping(action) => {
+                let bindings = keys::parse_bindings(input.unwrap_or_default())?;
+
+                // --- Note ---
+                // Only the same keys are taken, a binding can still start with the keys of another
+                // one, like `g` and `gg`
+                for binding in &bindings {
+                    let taken_by = Action::find(&config.key_mapping, binding.get_keys())
+                        .filter(|taken_action| taken_action != action);
+
+                    if let Some(taken_action) = taken_by {
+                        return Err(format!(
+                            "Key '{binding}' is already used by '{}'",
+                            taken_action.as_str()
+                        ));
+                    }
+                }
+
+                for (map_action, map_bindings) in config.key_mapping.iter_mut() {
+                    if map_action == action {
+                        *map_bindings = bindings.clone();
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn needs_input(&self) -> bool {
+        matches!(self, Setting::HideMenuTimeout | Setting::KeyMapping(_))
+    }
+}
+
+pub struct Settings {
+    settings: Vec<Setting>,
+    selected_index: u16,
+    scroll_offset: Cell<usize>,
+    message: Option<String>,
+    region: Cell<Region>,
+}
+
+impl Settings {
+    pub fn init() -> Self {
+        Settings {
+            settings: Setting::iterate(),
+            selected_index: 0,
+            scroll_offset: Cell::new(0),
+            message: None,
+            region: Cell::new(Region::default()),
+        }
+    }
+
+    fn listen_action(&mut self, action: &Action, config: &mut Config) {
+        match action {
+            Action::Up => {
+                self.selected_index = self.selected_index.saturating_sub(1);
+            }
+            Action::Down => {
+                let last_index = (self.settings.len() - 1) as u16;
+
+                self.selected_index = (self.selected_index + 1).min(last_index);
+            }
+            Action::Mark => {
+                let setting = self.settings[self.selected_index as usize].clone();
+
+                let input = if setting.needs_input() {
+                    match self.prompt(&setting, config) {
+                        Some(input) => Some(input),
+                        None => return,
+                    }
+                } else {
+                    None
+                };
+
+                self.message = match setting.apply(config, input.as_deref()) {
+                    Ok(()) if setting == Setting::StorageBackend => {
+                        config.save();
+                        Some(String::from("Storage backend is used from the next start"))
+                    }
+                    Ok(()) => {
+                        config.save();
+                        None
+                    }
+                    Err(err) => Some(err),
+                };
+
+                Screen::clear();
+            }
+            _ => {}
+        }
+    }
+
+    fn prompt(&self, setting: &Setting, config: &Config) -> Option<String> {
+        let prompt = format!(
+            "{LIST_LEFT_MARGIN}{WRAPPER}{name:<width$}",
+            name = setting.name(),
+            width = NAME_WIDTH
+        );
+        let x_offset = prompt.len() as u16;
+        let y_offset =
+            self.region.get().top + self.selected_index - self.scroll_offset.get() as u16;
+        let value = setting.value(config);
+
+        Cursor::show();
+        Cursor::place(x_offset, y_offset);
+
+        let mut rl = Editor::<()>::new().unwrap();
+        let input = rl.readline_with_initial(&prompt, (&value, "")).ok();
+
+        Cursor::hide();
+        Screen::clear();
+
+        input
+    }
+}
+
+impl EventHandler for Settings {}
+
+impl Tab for Settings {
+    fn title(&self) -> String {
+        TITLE.to_owned()
+    }
+
+    fn render(&self, region: Region, config: &Config) {
+        let (start, end) = get_visible_range(
+            &vec![1; self.settings.len()],
+            self.scroll_offset.get(),
+            self.selected_index as usize,
+            region.bottom.saturating_sub(region.top + 1),
+        );
+
+        self.region.set(region);
+
+        if start != self.scroll_offset.get() {
+            self.scroll_offset.set(start);
+            Screen::clear();
+        }
+
+        for (index, setting) in self.settings.iter().enumerate().take(end).skip(start) {
+            Cursor::place(
+                LIST_LEFT_MARGIN.len() as u16,
+                region.top + (index - start) as u16,
+            );
+
+            let selection = if index == self.selected_index as usize {
+                Some(&config.selection_style)
+            } else {
+                None
+            };
+
+            let print_style = PrintStyle {
+                selection,
+                strikethrough: false,
+                spacing: Some(LIST_SPACING),
+                tags: false,
+            };
+
+            Selection::print_styled(&setting.format(config), print_style);
+        }
+
+        if start > 0 || end < self.settings.len() {
+            Print::scroll_indicator(start, end, self.settings.len());
+        }
+
+        if let Some(message) = &self.message {
+            Print::status(message);
+        }
+
+        Print::finsih();
+    }
+
+    fn handle_action(&mut self, action: &Action, config: &mut Config) {
+        self.listen_action(action, config);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::Backend;
+
+    #[test]
+    fn test_toggle_flag() {
+        let mut config = Config::default();
+
+        Setting::AutoHideMenu.apply(&mut config, None).unwrap();
+
+        assert!(config.auto_hide_menu);
+    }
+
+    #[test]
+    fn test_cycle_selection_style() {
+        let mut config = Config::default();
+
+        Setting::SelectionStyle.apply(&mut config, None).unwrap();
+        assert_eq!(config.selection_style, Selection::Tilde);
+
+        for _ in 0..3 {
+            Setting::SelectionStyle.apply(&mut config, None).unwrap();
+        }
+        assert_eq!(config.selection_style, Selection::Brackets);
+    }
+
+    #[test]
+    fn test_set_hide_menu_timeout() {
+        let mut config = Config::default();
+
+        assert!(Setting::HideMenuTimeout
+            .apply(&mut config, Some("70000"))
+            .is_err());
+        assert!(Setting::HideMenuTimeout
+            .apply(&mut config, Some("abc"))
+            .is_err());
+
+        Setting::HideMenuTimeout
+            .apply(&mut config, Some("1500"))
+            .unwrap();
+        assert_eq!(config.hide_menu_timeout, 1500);
+    }
+
+    #[test]
+    fn test_switch_storage_backend() {
+        let mut config = Config::default();
+        let result = Setting::StorageBackend.apply(&mut config, None);
+
+        if cfg!(feature = "sqlite") {
+            assert_eq!(result, Ok(()));
+            assert_eq!(config.storage_backend, Backend::Sqlite);
+        } else {
+            assert!(result.is_err());
+            assert_eq!(config.storage_backend, Backend::Text);
+        }
+    }
+
+    #[test]
+    fn test_remap_key() {
+        let mut config = Config::default();
+
+        assert!(Setting::KeyMapping(Action::Up)
+            .apply(&mut config, Some("j"))
+            .is_err());
+
+        assert!(Setting::KeyMapping(Action::Up)
+            .apply(&mut config, Some("w <Home>"))
+            .is_err());
+
+        Setting::KeyMapping(Action::Up)
+            .apply(&mut config, Some("w <C-p>"))
+            .unwrap();
+        assert_eq!(Setting::KeyMapping(Action::Up).value(&config), "w <C-p>");
+
+        Setting::KeyMapping(Action::Top)
+            .apply(&mut config, Some("g"))
+            .unwrap();
+    }
+}
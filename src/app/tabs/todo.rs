@@ -1,34 +1,58 @@
+mod dates;
+mod history;
+mod palette;
+mod priority;
+mod search;
+mod tags;
+
 // TODO: refactor imports
+use super::{Region, Tab};
+use crate::app::event::{Event, EventHandler};
+use crate::app::filter::Filter;
 use crate::app::helper::{Cursor, Print, Screen};
+use crate::app::reminder::{Decision, DecisionRecord};
 use crate::app::selection::PrintStyle;
 use crate::app::selection::Selection;
 use crate::app::Action;
-
-use std::fs::{write, File};
-use std::io::{self, BufRead};
-use std::path::Path;
-
+use crate::config::Config;
+use crate::format::{Format, ImportError};
+use crate::storage::{ParseError, TodoStore};
+
+pub(crate) use dates::parse_date;
+pub use dates::{DATE_FORMAT, DAY_FORMAT};
+use history::History;
+pub use priority::Priority;
+use search::{Search, SearchPreview};
+pub use tags::{Tag, TagKind};
+
+use std::cell::{Cell, RefCell};
+use std::io;
+use std::rc::Rc;
+
+use core::cmp::Ordering;
 use core::str::FromStr;
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Local, NaiveDate, Utc};
 
 use termion;
-use termion::event::Key;
 
-use rustyline::{Editor, Result as RLResult};
+use rustyline::Editor;
 
-const DELIMITER: &'static str = "|";
-const WRAPPER: &'static str = " ";
-const LIST_MARK: &'static str = "·";
-const LIST_MARK_SELECTED: &'static str = "•";
-const LIST_SPACING: &'static str = " ";
-const LIST_LEFT_MARGIN: &'static str = "  ";
-const LIST_TOP_MARGIN: u16 = 2;
+const WRAPPER: &str = " ";
+const LIST_MARK: &str = "·";
+const LIST_MARK_SELECTED: &str = "•";
+const LIST_SPACING: &str = " ";
+const LIST_LEFT_MARGIN: &str = "  ";
+const TITLE: &str = "TODO";
+const DATE_SEPARATOR: &str = " · ";
+const TODAY_HEADER_HEIGHT: u16 = 2;
+const SEARCH_PROMPT: &str = "/";
+
+type Snapshot = (Vec<TodoItem>, u16);
 
 // TODO: add emojis in the future
-// TODO: think about page scroll when many todos
 
-fn generate_id(todo_list: &Vec<TodoItem>) -> u16 {
+fn generate_id(todo_list: &[TodoItem]) -> u16 {
     let mut ids: Vec<u16> = Vec::new();
 
     for item in todo_list {
@@ -46,71 +70,28 @@ fn generate_id(todo_list: &Vec<TodoItem>) -> u16 {
     panic!("Too many todo items");
 }
 
-fn read_todo<'a>(
-    path: &'a Path,
-    selection_style: &'a Selection,
-    key_mapping: &'a Vec<(Action, char)>,
-) -> Todo<'a> {
-    let file = File::open(path).unwrap_or_else(|error| {
-        panic!("Couldn't read todo.txt file: {error}");
-    });
-    let lines = io::BufReader::new(file).lines();
-
-    let mut todo_list: Vec<TodoItem> = Vec::new();
-
-    for line in lines {
-        let line = match line {
-            Ok(line) => line,
-            Err(error) => {
-                panic!("todo.txt file seems to be corrupted: {error}. Try deleting the file and restarting mindr. NOTE: deleting the file will destroy user's todo list data");
-            }
-        };
-
-        let item_config = line.split(DELIMITER).collect::<Vec<&str>>();
-
-        if item_config.len() != 5 {
-            panic!("todo.txt file seems to be corrupted: App configuration doesn't match. Check that configuration matches 'id|DateTime|DateTime|Status|Description' pattern or delete the file and restart mindr. NOTE: deleting the file will destroy user's todo list data");
-        }
-
-        // TODO: do sth with repetetive messages
-        // TODO: id is probably not needed at all
-        // TODO: also date_modified is currently not used
-        let date_modified = item_config[2].parse::<DateTime<Utc>>().expect("Coldn't parse the date in todo.txt, check that all dates are valid or delete the file and restart mindr. NOTE: deleting the file will destroy user's todo list data");
-
-        // TODO: first fix write
-        // let today = Utc::now().date();
-        // let modified = date_modified.date();
-
-        // if today > modified {
-        //     continue;
-        // }
-
-        let id = item_config[0].parse::<u16>().expect("Couldn't parse todo.txt id to u32, check that all ids are valid or delete the file and restart mindr. NOTE: deleting the file will destroy user's todo list data");
-        let date_created = item_config[1].parse::<DateTime<Utc>>().expect("Coldn't parse the date in todo.txt, check that all dates are valid or delete the file and restart mindr. NOTE: deleting the file will destroy user's todo list data");
-        let status = Status::from_str(&item_config[3]).unwrap_or_else(|err| {
-            eprintln!("Couldn't get todo item status: {err}. Setting to default status 'Todo'");
-            Status::Todo
-        });
-        let description = item_config[4].to_owned();
+pub(crate) fn get_visible_range(
+    heights: &[u16],
+    offset: usize,
+    selected: usize,
+    available: u16,
+) -> (usize, usize) {
+    let selected = selected.min(heights.len().saturating_sub(1));
+    let mut start = offset.min(selected);
+
+    while start < selected && heights[start..=selected].iter().sum::<u16>() > available {
+        start += 1;
+    }
 
-        let todo_item = TodoItem {
-            id,
-            date_created,
-            date_modified,
-            status,
-            description,
-        };
+    let mut end = start;
+    let mut used: u16 = 0;
 
-        todo_list.push(todo_item);
+    while end < heights.len() && (end == start || used + heights[end] <= available) {
+        used += heights[end];
+        end += 1;
     }
 
-    Todo {
-        todo_list,
-        key_mapping,
-        selection_style,
-        selected_index: 0,
-        path,
-    }
+    (start, end)
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -120,7 +101,7 @@ pub enum Status {
 }
 
 impl Status {
-    fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &'static str {
         match self {
             Status::Todo => "Todo",
             Status::Done => "Done",
@@ -132,10 +113,51 @@ impl FromStr for Status {
     type Err = &'static str;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "Todo" => Ok(Status::Todo),
-            "Done" => Ok(Status::Done),
-            _ => return Err("No such status availabe, try using 'Todo/Done'"),
+        match s.to_lowercase().as_str() {
+            "todo" => Ok(Status::Todo),
+            "done" => Ok(Status::Done),
+            _ => Err("No such status availabe, try using 'todo/done'"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SortMode {
+    Manual,
+    Due,
+    Scheduled,
+    Priority,
+}
+
+impl SortMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortMode::Manual => "manual",
+            SortMode::Due => "due",
+            SortMode::Scheduled => "scheduled",
+            SortMode::Priority => "priority",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            SortMode::Manual => SortMode::Due,
+            SortMode::Due => SortMode::Scheduled,
+            SortMode::Scheduled => SortMode::Priority,
+            SortMode::Priority => SortMode::Manual,
+        }
+    }
+
+    fn compare(&self, a: &TodoItem, b: &TodoItem) -> Ordering {
+        fn compare_optional<T: Ord>(a: Option<T>, b: Option<T>) -> Ordering {
+            (a.is_none(), a).cmp(&(b.is_none(), b))
+        }
+
+        match self {
+            SortMode::Manual => Ordering::Equal,
+            SortMode::Due => compare_optional(a.due, b.due),
+            SortMode::Scheduled => compare_optional(a.scheduled, b.scheduled),
+            SortMode::Priority => compare_optional(a.priority, b.priority),
         }
     }
 }
@@ -144,19 +166,191 @@ impl FromStr for Status {
 // TODO: item (as well as Status) is used in both `todo.rs` an `done.rs` maybe it should be in a separate mod
 #[derive(Debug, Clone, PartialEq)]
 pub struct TodoItem {
-    id: u16,
+    pub(crate) id: u16,
     pub date_created: DateTime<Utc>,
     pub date_modified: DateTime<Utc>,
     pub status: Status,
     pub description: String,
+    pub due: Option<NaiveDate>,
+    pub scheduled: Option<NaiveDate>,
+    pub priority: Option<Priority>,
+    pub date_completed: Option<DateTime<Utc>>,
+    pub decisions: Vec<DecisionRecord>,
 }
 
 impl TodoItem {
+    pub(crate) fn new(id: u16, description: &str) -> Self {
+        TodoItem {
+            id,
+            date_created: Utc::now(),
+            date_modified: Utc::now(),
+            status: Status::Todo,
+            description: description.to_owned(),
+            due: None,
+            scheduled: None,
+            priority: None,
+            date_completed: None,
+            decisions: Vec::new(),
+        }
+    }
+
+    pub fn id(&self) -> u16 {
+        self.id
+    }
+
+    pub fn set_status(&mut self, status: Status) {
+        let now = Utc::now();
+
+        self.date_completed = match status {
+            Status::Done => Some(now),
+            Status::Todo => None,
+        };
+        self.status = status;
+        self.date_modified = now;
+    }
+
+    // --- Note ---
+    // Items completed by older versions have no completion time, their last change is the closest
+    // guess
+    pub fn get_date_completed(&self) -> Option<DateTime<Utc>> {
+        match self.status {
+            Status::Done => Some(self.date_completed.unwrap_or(self.date_modified)),
+            Status::Todo => None,
+        }
+    }
+
+    // --- Note ---
+    // Open items are carried over from day to day unless they are scheduled for a later one and
+    // aren't due on the day, done items stay only for the day they were completed on. Days start
+    // at the local midnight
+    pub fn is_shown_on(&self, day: NaiveDate) -> bool {
+        match self.get_date_completed() {
+            Some(date) => date.with_timezone(&Local).date_naive() == day,
+            None if self.due == Some(day) => true,
+            None => self.scheduled.is_none_or(|scheduled| scheduled <= day),
+        }
+    }
+
+    pub fn set_input(&mut self, input: &str) {
+        self.priority = None;
+        self.due = None;
+        self.scheduled = None;
+        self.set_description(input);
+    }
+
+    pub fn set_description(&mut self, input: &str) {
+        let (priority, input) = Priority::parse_input(input);
+        let input = dates::parse_input(input, Local::now().date_naive());
+
+        self.priority = priority.unwrap_or(self.priority);
+        self.due = input.due.unwrap_or(self.due);
+        self.scheduled = input.scheduled.unwrap_or(self.scheduled);
+        self.description = input.description;
+        self.date_modified = Utc::now();
+    }
+
+    pub fn get_input(&self) -> String {
+        let mut input = match self.priority {
+            Some(priority) => format!("({}) {}", priority.as_str(), self.description),
+            None => self.description.clone(),
+        };
+
+        for (key, date) in [
+            (dates::DUE_KEY, self.due),
+            (dates::SCHEDULED_KEY, self.scheduled),
+        ] {
+            if let Some(date) = date {
+                input.push_str(&format!(" {key}:{}", date.format(DATE_FORMAT)));
+            }
+        }
+
+        input
+    }
+
+    pub fn get_tags(&self) -> Vec<Tag> {
+        Tag::parse_all(&self.description)
+    }
+
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        self.status == Status::Todo && self.due.is_some_and(|due| due < today)
+    }
+
+    fn get_dates(&self, today: NaiveDate) -> Vec<(String, Option<termion::color::AnsiValue>)> {
+        let mut labels = Vec::new();
+
+        if let Some(due) = self.due {
+            let color = if self.is_overdue(today) {
+                Some(palette::OVERDUE)
+            } else if due == today && self.status == Status::Todo {
+                Some(palette::DUE_TODAY)
+            } else {
+                None
+            };
+
+            labels.push((format!("due {}", dates::format_date(due, today)), color));
+        }
+
+        if let Some(scheduled) = self.scheduled {
+            labels.push((
+                format!("sched {}", dates::format_date(scheduled, today)),
+                None,
+            ));
+        }
+
+        labels
+    }
+
+    // --- Note ---
+    // Colored text has the same length on the screen as the plain one, so the text without
+    // `config` is used to count line heights
+    pub(crate) fn format(
+        &self,
+        today: NaiveDate,
+        config: Option<&Config>,
+        search: Option<&Search>,
+    ) -> String {
+        let paint = |text: &str, color: Option<termion::color::AnsiValue>| match color {
+            Some(color) if config.is_some() => format!(
+                "{fg}{text}{reset}",
+                fg = termion::color::Fg(color),
+                reset = termion::color::Fg(termion::color::Reset),
+            ),
+            _ => text.to_owned(),
+        };
+
+        let description = match search {
+            Some(search) if config.is_some() => search.highlight(&self.description),
+            _ => self.description.clone(),
+        };
+
+        let mut description = match self.priority {
+            Some(priority) => {
+                let color = config
+                    .filter(|config| config.priority_colors)
+                    .map(|_| priority.get_color());
+
+                format!(
+                    "{} {}",
+                    paint(&format!("({})", priority.as_str()), color),
+                    description
+                )
+            }
+            None => description,
+        };
+
+        for (text, color) in self.get_dates(today) {
+            description.push_str(DATE_SEPARATOR);
+            description.push_str(&paint(&text, color));
+        }
+
+        TodoItem::format_description(&description)
+    }
+
     fn format_description(description: &str) -> String {
         format!("{WRAPPER}{LIST_MARK}{LIST_SPACING}{description}{WRAPPER}")
     }
 
-    fn get_line_height(text: &str) -> u16 {
+    pub(crate) fn get_line_height(text: &str) -> u16 {
         let (x_size, _) = Screen::get_size();
         let y_offset = (text.len() as f32) / (x_size as f32);
 
@@ -167,93 +361,467 @@ impl TodoItem {
 #[derive(Debug, Clone)]
 pub struct Todo<'a> {
     pub todo_list: Vec<TodoItem>,
-    key_mapping: &'a Vec<(Action, char)>,
-    selection_style: &'a Selection,
     selected_index: u16,
-    path: &'a Path,
+    store: Rc<dyn TodoStore + 'a>,
+    load_errors: Vec<ParseError>,
+    save_error: Option<String>,
+    history: History<Snapshot>,
+    scroll_offset: Cell<usize>,
+    sort_mode: SortMode,
+    filter: Filter,
+    search: Option<Search>,
+    is_search_preview: bool,
+    is_today_view: bool,
+    region: Cell<Region>,
 }
 
 impl<'a> Todo<'a> {
-    // TODO: somehow improve amount of args and path
-    pub fn init(
-        selection_style: &'a Selection,
-        key_mapping: &'a Vec<(Action, char)>,
-        path: &'a Path,
-    ) -> Self {
-        if !path.exists() {
-            File::create(path).expect("Couldn't create todo list storage file");
-
-            return Self {
-                key_mapping,
-                selection_style,
-                todo_list: Vec::new(),
-                selected_index: 0,
-                path,
-            };
+    pub fn init(store: impl TodoStore + 'a) -> io::Result<Self> {
+        let (todo_list, load_errors) = store.load()?;
+
+        let todo = Self {
+            todo_list,
+            selected_index: 0,
+            store: Rc::new(store),
+            load_errors,
+            save_error: None,
+            history: History::init(),
+            scroll_offset: Cell::new(0),
+            sort_mode: SortMode::Manual,
+            filter: Filter::default(),
+            search: None,
+            is_search_preview: false,
+            is_today_view: false,
+            region: Cell::new(Region::default()),
+        };
+
+        if !todo.load_errors.is_empty() {
+            todo.write()?;
         }
 
-        read_todo(&path, &selection_style, &key_mapping)
+        Ok(todo)
     }
 
-    fn remove_selected_todo(&mut self) {
-        if self.key_mapping.len() < 1 {
+    pub fn reload(&mut self) {
+        let Ok((todo_list, load_errors)) = self.store.load() else {
             return;
+        };
+
+        if todo_list != self.todo_list {
+            self.record_history();
+            self.todo_list = todo_list;
+
+            let last_index = self.get_view().len().saturating_sub(1) as u16;
+
+            self.selected_index = self.selected_index.min(last_index);
+            Screen::clear();
         }
 
-        let list: Vec<TodoItem> = self
-            .todo_list
-            .clone()
-            .into_iter()
-            .enumerate()
-            .filter(|(index, _)| *index as u16 != self.selected_index)
-            .map(|(_, item)| item)
-            .collect();
+        self.load_errors = load_errors;
 
-        if self.selected_index > 0 && self.selected_index > list.len() as u16 - 1 {
-            self.selected_index -= 1;
+        if !self.load_errors.is_empty() {
+            self.handle_save(self.write());
         }
+    }
+
+    pub fn add(&mut self, input: &str) -> &TodoItem {
+        let mut todo_item = TodoItem::new(generate_id(&self.todo_list), "");
+
+        todo_item.set_input(input);
+        self.todo_list.push(todo_item);
 
-        self.todo_list = list;
+        self.todo_list.last().unwrap()
     }
 
-    // TODO: probably rename to save
-    fn write(&self) {
-        // TODO: implement writing on lines
-        let contents = &self.todo_list;
-        let contents: Vec<String> = contents
-            .into_iter()
-            .map(
-                |TodoItem {
-                     id,
-                     date_created,
-                     date_modified,
-                     status,
-                     description,
-                 }| {
-                    format!(
-                        "{id}|{date_created}|{date_modified}|{status}|{description}",
-                        status = status.as_str()
-                    )
-                },
-            )
+    pub fn export(&self, format: &Format) -> String {
+        format.export(&self.todo_list)
+    }
+
+    pub fn import(&mut self, format: &Format, text: &str) -> Result<usize, ImportError> {
+        let items = format.import(text)?;
+        let count = items.len();
+
+        for mut item in items {
+            if self
+                .todo_list
+                .iter()
+                .any(|todo_item| todo_item.id == item.id)
+            {
+                item.id = generate_id(&self.todo_list);
+            }
+
+            self.todo_list.push(item);
+        }
+
+        Ok(count)
+    }
+
+    pub fn unmark(&mut self, id: u16) {
+        let Some(index) = self.todo_list.iter().position(|item| item.id == id) else {
+            return;
+        };
+
+        self.record_history();
+        self.todo_list[index].set_status(Status::Todo);
+        self.handle_save(self.write_update(id));
+    }
+
+    pub fn apply_decision(&mut self, id: u16, decision: &Decision) {
+        let Some(index) = self.todo_list.iter().position(|item| item.id == id) else {
+            return;
+        };
+        let today = Local::now().date_naive();
+
+        self.record_history();
+
+        let item = &mut self.todo_list[index];
+
+        match decision {
+            Decision::CarryOver => item.scheduled = Some(today),
+            Decision::Reschedule(date) => item.scheduled = Some(*date),
+            Decision::Drop => item.set_status(Status::Done),
+        }
+
+        item.decisions.push(DecisionRecord {
+            date: today,
+            decision: decision.clone(),
+        });
+        item.date_modified = Utc::now();
+        self.handle_save(self.write_update(id));
+    }
+
+    pub fn find_mut(&mut self, id: u16) -> Option<&mut TodoItem> {
+        self.todo_list.iter_mut().find(|item| item.id == id)
+    }
+
+    pub fn remove(&mut self, id: u16) -> Option<TodoItem> {
+        let index = self.todo_list.iter().position(|item| item.id == id)?;
+
+        Some(self.todo_list.remove(index))
+    }
+
+    fn get_view(&self) -> Vec<usize> {
+        let today = Local::now().date_naive();
+        let mut view: Vec<usize> = (0..self.todo_list.len())
+            .filter(|index| {
+                let item = &self.todo_list[*index];
+
+                (!self.is_today_view || item.is_shown_on(today))
+                    && self.filter.matches(item)
+                    && (!self.is_search_preview || self.search.is_none() || self.is_match(item))
+            })
             .collect();
 
-        // TODO: consider using File::write()
-        write(self.path, contents.join("\n")).unwrap_or_else(|err| {
-            panic!("Couldn't save updated todo list: {err}");
+        view.sort_by(|a, b| {
+            self.sort_mode
+                .compare(&self.todo_list[*a], &self.todo_list[*b])
         });
+
+        view
+    }
+
+    pub fn set_today_view(&mut self, is_today_view: bool) {
+        if self.is_today_view == is_today_view {
+            return;
+        }
+
+        let selected_id = self.get_selected().map(|index| self.todo_list[index].id);
+
+        self.is_today_view = is_today_view;
+        self.selected_index = 0;
+
+        if let Some(id) = selected_id {
+            self.select(id);
+        }
+    }
+
+    fn get_top_margin(&self) -> u16 {
+        let top = self.region.get().top;
+
+        if self.is_today_view {
+            top + TODAY_HEADER_HEIGHT
+        } else {
+            top
+        }
+    }
+
+    pub fn get_filter(&self) -> &Filter {
+        &self.filter
+    }
+
+    pub fn set_filter(&mut self, filter: Filter) {
+        self.filter = filter;
+        self.selected_index = 0;
+        self.scroll_offset.set(0);
+    }
+
+    fn is_match(&self, item: &TodoItem) -> bool {
+        self.search
+            .as_ref()
+            .is_some_and(|search| search.is_match(&item.description))
+    }
+
+    fn select_match(&mut self, direction: i32) {
+        let view = self.get_view();
+        let len = view.len() as i32;
+
+        for step in 1..=len {
+            let index = (self.selected_index as i32 + direction * step).rem_euclid(len);
+
+            if self.is_match(&self.todo_list[view[index as usize]]) {
+                self.selected_index = index as u16;
+                return;
+            }
+        }
+    }
+
+    fn search(&mut self, config: &Config) {
+        let (_, y_size) = Screen::get_size();
+        let mut preview = self.clone();
+
+        preview.is_search_preview = true;
+        preview.selected_index = 0;
+        preview.scroll_offset.set(0);
+
+        let mut rl = Editor::<SearchPreview>::new().unwrap();
+
+        rl.set_helper(Some(SearchPreview {
+            todo: RefCell::new(preview),
+            config,
+        }));
+
+        let initial = self.search.as_ref().map_or("", |search| search.as_str());
+
+        Cursor::show();
+        Cursor::place(1, y_size.saturating_sub(2));
+
+        let line = rl.readline_with_initial(SEARCH_PROMPT, (initial, "")).ok();
+
+        Cursor::hide();
+        Screen::clear();
+
+        match line.map(|line| Search::parse(&line)) {
+            Some(Ok(Some(search))) => {
+                self.search = Some(search);
+                self.select_match(1);
+            }
+            Some(Ok(None)) => self.search = None,
+            Some(Err(_)) | None => {}
+        }
+    }
+
+    fn get_selected(&self) -> Option<usize> {
+        self.get_view().get(self.selected_index as usize).copied()
+    }
+
+    fn select(&mut self, id: u16) {
+        if let Some(index) = self
+            .get_view()
+            .iter()
+            .position(|index| self.todo_list[*index].id == id)
+        {
+            self.selected_index = index as u16;
+        }
+    }
+
+    fn remove_selected_todo(&mut self) {
+        let Some(selected) = self.get_selected() else {
+            return;
+        };
+
+        let id = self.todo_list.remove(selected).id;
+        let last_index = self.get_view().len().saturating_sub(1) as u16;
+
+        self.selected_index = self.selected_index.min(last_index);
+        self.handle_save(self.write_delete(id));
+    }
+
+    fn record_history(&mut self) {
+        self.history
+            .record((self.todo_list.clone(), self.selected_index));
+    }
+
+    fn restore_snapshot(&mut self, snapshot: Option<Snapshot>) {
+        if let Some((todo_list, selected_index)) = snapshot {
+            self.todo_list = todo_list;
+            self.selected_index = selected_index;
+            self.handle_save(self.write());
+        }
+    }
+
+    fn undo(&mut self) {
+        let snapshot = self
+            .history
+            .undo((self.todo_list.clone(), self.selected_index));
+
+        self.restore_snapshot(snapshot);
+    }
+
+    fn redo(&mut self) {
+        let snapshot = self
+            .history
+            .redo((self.todo_list.clone(), self.selected_index));
+
+        self.restore_snapshot(snapshot);
+    }
+
+    // --- Note ---
+    // Items are only moved in the manual order, otherwise they would jump back right away
+    fn move_selected_todo(&mut self, offset: i32) {
+        let view = self.get_view();
+        let index = self.selected_index as i32;
+        let target = index + offset;
+
+        if self.sort_mode != SortMode::Manual || target < 0 || target >= view.len() as i32 {
+            return;
+        }
+
+        self.record_history();
+        self.todo_list
+            .swap(view[index as usize], view[target as usize]);
+        self.selected_index = target as u16;
+        self.handle_save(self.write());
+    }
+
+    fn change_selected_priority(&mut self, change: fn(Option<Priority>) -> Option<Priority>) {
+        let Some(selected) = self.get_selected() else {
+            return;
+        };
+
+        let item = &self.todo_list[selected];
+        let (id, priority) = (item.id, change(item.priority));
+
+        if priority == item.priority {
+            return;
+        }
+
+        self.record_history();
+        self.todo_list[selected].priority = priority;
+        self.todo_list[selected].date_modified = Utc::now();
+        self.select(id);
+        self.handle_save(self.write_update(id));
+    }
+
+    fn cycle_sort_mode(&mut self) {
+        let selected_id = self.get_selected().map(|index| self.todo_list[index].id);
+
+        self.sort_mode = self.sort_mode.next();
+
+        if let Some(id) = selected_id {
+            self.select(id);
+        }
+    }
+
+    // TODO: probably rename to save
+    pub fn write(&self) -> io::Result<()> {
+        self.store.save(&self.todo_list)
+    }
+
+    pub fn write_insert(&self, id: u16) -> io::Result<()> {
+        match self.todo_list.iter().find(|item| item.id == id) {
+            Some(item) => self.store.insert(item),
+            None => Ok(()),
+        }
+    }
+
+    pub fn write_update(&self, id: u16) -> io::Result<()> {
+        match self.todo_list.iter().find(|item| item.id == id) {
+            Some(item) => self.store.update(item),
+            None => Ok(()),
+        }
+    }
+
+    pub fn write_delete(&self, id: u16) -> io::Result<()> {
+        self.store.delete(id)
+    }
+
+    // --- Note ---
+    // Single item changes fail with `NotFound` when the item was removed outside of mindr before
+    // the file watcher noticed it, the list is loaded again then instead of writing over it
+    fn handle_save(&mut self, result: io::Result<()>) {
+        match result {
+            Ok(()) => {}
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                self.reload();
+                self.save_error = Some(format!(
+                    "{err}, todo list was changed outside of mindr and is loaded again"
+                ));
+            }
+            Err(err) => self.save_error = Some(format!("Couldn't save todo list: {err}")),
+        }
+    }
+
+    pub fn take_save_error(&mut self) -> Option<String> {
+        self.save_error.take()
+    }
+
+    pub fn get_load_errors(&self) -> &[ParseError] {
+        &self.load_errors
+    }
+
+    fn get_heights(&self, view: &[usize], today: NaiveDate) -> Vec<u16> {
+        view.iter()
+            .map(|index| {
+                TodoItem::get_line_height(&self.todo_list[*index].format(today, None, None))
+            })
+            .collect()
+    }
+
+    fn get_list_height(&self, reserved_rows: u16) -> u16 {
+        self.region
+            .get()
+            .bottom
+            .saturating_sub(self.get_top_margin() + 1 + reserved_rows)
+    }
+
+    fn get_visible_range(&self, view: &[usize], reserved_rows: u16) -> (usize, usize) {
+        get_visible_range(
+            &self.get_heights(view, Local::now().date_naive()),
+            self.scroll_offset.get(),
+            self.selected_index as usize,
+            self.get_list_height(reserved_rows),
+        )
+    }
+
+    fn get_page_size(&self) -> u16 {
+        let (start, end) = self.get_visible_range(&self.get_view(), 0);
+
+        (end - start).max(1) as u16
     }
 
-    pub fn render(&self) {
-        let mut cursor_y = 2;
+    fn render_list(&self, config: &Config, reserved_rows: u16) {
+        let today = Local::now().date_naive();
+        let view = self.get_view();
+        let (start, end) = self.get_visible_range(&view, reserved_rows);
+
+        if start != self.scroll_offset.get() {
+            self.scroll_offset.set(start);
+            Screen::clear();
+        }
+
+        if self.is_today_view {
+            Cursor::place(LIST_LEFT_MARGIN.len() as u16, self.region.get().top);
+            print!(
+                "{bold}Today{reset}{DATE_SEPARATOR}{date}",
+                bold = termion::style::Bold,
+                reset = termion::style::Reset,
+                date = today.format(DAY_FORMAT),
+            );
+        }
+
+        let mut cursor_y = self.get_top_margin();
 
-        for item in &self.todo_list {
+        for (index, item) in view
+            .iter()
+            .map(|index| &self.todo_list[*index])
+            .enumerate()
+            .take(end)
+            .skip(start)
+        {
             Cursor::place(LIST_LEFT_MARGIN.len() as u16, cursor_y);
 
-            let index = &self.todo_list.iter().position(|todo| todo == item).unwrap();
-            let selected_index = &(self.selected_index as usize);
-            let selection = if selected_index == index {
-                Some(self.selection_style)
+            let selection = if index == self.selected_index as usize {
+                Some(&config.selection_style)
             } else {
                 None
             };
@@ -262,147 +830,266 @@ impl<'a> Todo<'a> {
                 selection,
                 strikethrough: item.status == Status::Done,
                 spacing: Some(LIST_SPACING),
+                tags: true,
             };
 
-            let text = TodoItem::format_description(&item.description);
-            let y_offset = TodoItem::get_line_height(&text);
+            let text = item.format(today, Some(config), self.search.as_ref());
+            let y_offset = TodoItem::get_line_height(&item.format(today, None, None));
 
             cursor_y += y_offset;
 
             Selection::print_styled(text.as_str(), print_style);
         }
 
+        if start > 0 || end < view.len() {
+            Print::scroll_indicator(start, end, view.len());
+        }
+
+        let mut status = Vec::new();
+
+        if self.sort_mode != SortMode::Manual {
+            status.push(format!("sorted by {}", self.sort_mode.as_str()));
+        }
+
+        if !self.filter.is_empty() {
+            status.push(format!("filter: {}", self.filter));
+        }
+
+        if let Some(search) = &self.search {
+            status.push(format!("search: {}", search.as_str()));
+        }
+
+        if !status.is_empty() {
+            Print::status(&status.join(DATE_SEPARATOR));
+        }
+
         Print::finsih();
     }
 
-    pub fn listen_keys(&mut self, key: &Key) {
-        match key {
-            // TODO: Maybe make a macro (learn more about macros)
-            // https://stackoverflow.com/questions/63876773/how-complicated-can-a-match-pattern-be-trying-to-convert-macro-from-termion-to
-            Key::Char(ch) if ch == &Action::Up.as_char(self.key_mapping) => {
-                if self.selected_index != 0 {
-                    self.selected_index -= 1;
-                }
+    fn read_input(initial: (&str, &str), x_offset: u16, y_offset: u16) -> Option<String> {
+        let prompt = format!("{LIST_LEFT_MARGIN}{LIST_MARK_SELECTED}{WRAPPER}");
+
+        Cursor::show();
+        Cursor::place(x_offset, y_offset);
+
+        let mut rl = Editor::<()>::new().unwrap();
+        let line = rl.readline_with_initial(&prompt, initial).ok();
+
+        Cursor::hide();
+
+        line
+    }
+
+    fn listen_action(&mut self, action: &Action, config: &Config) {
+        let last_index = self.get_view().len().saturating_sub(1) as u16;
+        let x_offset = format!("{LIST_LEFT_MARGIN}{LIST_MARK_SELECTED}{WRAPPER}").len() as u16;
+
+        match action {
+            Action::Up => {
+                self.selected_index = self.selected_index.saturating_sub(1);
             }
-            Key::Char(ch) if ch == &Action::get_action_char(self.key_mapping, Action::Down) => {
-                if self.todo_list.len() > 0
-                    && self.selected_index < (self.todo_list.len() - 1) as u16
-                {
-                    self.selected_index += 1;
-                }
+            Action::Down => {
+                self.selected_index = (self.selected_index + 1).min(last_index);
             }
-            Key::Char(ch) if ch == &Action::get_action_char(self.key_mapping, Action::AddTodo) => {
-                let prompt = format!("{LIST_LEFT_MARGIN}{LIST_MARK_SELECTED}{WRAPPER}");
-                let x_offset = prompt.len() as u16;
+            Action::AddTodo => {
+                let today = Local::now().date_naive();
                 // --- Note ---
-                // We get offset to the last todo item and put cursor under it (+ 1)
-                let y_offset = self.get_y_offset(self.todo_list.last().unwrap().id) + 1;
-
-                Cursor::show();
-                Cursor::place(x_offset, y_offset);
-
-                let mut rl = Editor::<()>::new().unwrap();
-
-                while let RLResult::Ok(line) = rl.readline(&prompt) {
-                    if line.len() > 0 {
-                        let todo_item = TodoItem {
-                            id: generate_id(&self.todo_list),
-                            date_created: Utc::now(),
-                            date_modified: Utc::now(),
-                            status: Status::Todo,
-                            description: line.trim().to_owned(),
-                        };
-
-                        self.todo_list.push(todo_item);
-                        self.write();
+                // We scroll to the last todo item leaving one row for the prompt and put cursor
+                // under it
+                let y_offset = match self.get_view().last() {
+                    Some(&last) => {
+                        let last_item = &self.todo_list[last];
+
+                        self.selected_index = last_index;
+                        self.render_list(config, 1);
+
+                        self.get_y_offset(last_item.id)
+                            + TodoItem::get_line_height(&last_item.format(today, None, None))
                     }
+                    None => self.get_top_margin(),
+                };
+
+                let tags: String = self
+                    .filter
+                    .get_tags()
+                    .iter()
+                    .map(|tag| format!(" {tag}"))
+                    .collect();
+
+                if let Some(line) = Todo::read_input(("", &tags), x_offset, y_offset) {
+                    if !line.trim().is_empty() {
+                        self.record_history();
+
+                        let id = self.add(&line).id;
 
-                    break;
+                        self.select(id);
+                        self.handle_save(self.write_insert(id));
+                    }
                 }
 
-                Cursor::hide();
                 Screen::clear();
             }
-            Key::Char(ch)
-                if ch == &Action::get_action_char(self.key_mapping, Action::RemoveTodo) =>
-            {
+            Action::RemoveTodo => {
+                if self.todo_list.is_empty() {
+                    return;
+                }
+
+                self.record_history();
                 self.remove_selected_todo();
-                self.write();
 
                 Screen::clear();
             }
-            Key::Char(ch) if ch == &Action::get_action_char(self.key_mapping, Action::Mark) => {
-                let list: Vec<TodoItem> = self
-                    .todo_list
-                    .clone()
-                    .into_iter()
-                    .enumerate()
-                    .map(|(index, mut item)| {
-                        if index as u16 == self.selected_index {
-                            item.status = match item.status {
-                                Status::Done => Status::Todo,
-                                Status::Todo => Status::Done,
-                            }
-                        }
-                        item
-                    })
-                    .collect();
-
-                self.todo_list = list;
-                self.write();
-            }
-            Key::Char(ch) if ch == &Action::get_action_char(self.key_mapping, Action::EditTodo) => {
-                // TODO: add update of date modified
-                if self.todo_list.len() == 0 {
+            Action::Mark => {
+                let Some(selected) = self.get_selected() else {
                     return;
-                }
+                };
+
+                self.record_history();
+
+                let item = &mut self.todo_list[selected];
 
-                let selected_todo = &self.todo_list[self.selected_index as usize];
+                item.set_status(match item.status {
+                    Status::Done => Status::Todo,
+                    Status::Todo => Status::Done,
+                });
 
-                let prompt = format!("{LIST_LEFT_MARGIN}{LIST_MARK_SELECTED}{WRAPPER}");
-                let x_offset = prompt.len() as u16;
-                let y_offset = self.get_y_offset(selected_todo.id);
+                let id = item.id;
 
-                Cursor::show();
-                Cursor::place(x_offset, y_offset);
+                self.handle_save(self.write_update(id));
+            }
+            Action::EditTodo => {
+                let Some(selected) = self.get_selected() else {
+                    return;
+                };
 
-                let description = &selected_todo.description;
+                let selected_todo = &self.todo_list[selected];
+                let id = selected_todo.id;
+                let input = selected_todo.get_input();
+                let y_offset = self.get_y_offset(id);
 
-                let mut rl = Editor::<()>::new().unwrap();
+                if let Some(line) = Todo::read_input((&input, ""), x_offset, y_offset) {
+                    if line.trim() != input {
+                        self.record_history();
+                    }
 
-                while let RLResult::Ok(line) = rl.readline_with_initial(&prompt, (&description, ""))
-                {
-                    if line.trim().len() > 0 {
-                        self.todo_list[self.selected_index as usize].description =
-                            line.trim().to_owned();
-                    } else {
+                    if line.trim().is_empty() {
                         self.remove_selected_todo();
+                    } else if line.trim() != input {
+                        self.todo_list[selected].set_input(&line);
+                        self.select(id);
+                        self.handle_save(self.write_update(id));
                     }
-                    break;
                 }
 
-                Cursor::hide();
-                self.write();
+                Screen::clear();
+            }
+            Action::Undo => {
+                self.undo();
+
+                Screen::clear();
+            }
+            Action::Redo => {
+                self.redo();
+
+                Screen::clear();
+            }
+            Action::PageUp => {
+                self.selected_index = self.selected_index.saturating_sub(self.get_page_size());
+            }
+            Action::PageDown => {
+                self.selected_index = (self.selected_index + self.get_page_size()).min(last_index);
+            }
+            Action::Top => {
+                self.selected_index = 0;
+            }
+            Action::Bottom => {
+                self.selected_index = last_index;
+            }
+            Action::MoveUp => {
+                self.move_selected_todo(-1);
+            }
+            Action::MoveDown => {
+                self.move_selected_todo(1);
+            }
+            Action::Sort => {
+                self.cycle_sort_mode();
+
+                Screen::clear();
+            }
+            Action::Filter => {
+                if let Some(filter) = self.filter.prompt(&self.todo_list) {
+                    self.set_filter(filter);
+                }
+            }
+            Action::Search => {
+                self.search(config);
+            }
+            Action::NextMatch => {
+                self.select_match(1);
+            }
+            Action::PrevMatch => {
+                self.select_match(-1);
+            }
+            Action::RaisePriority => {
+                self.change_selected_priority(Priority::raise);
+
+                Screen::clear();
+            }
+            Action::LowerPriority => {
+                self.change_selected_priority(Priority::lower);
+
+                Screen::clear();
             }
             _ => {}
         }
     }
 
     fn get_y_offset(&self, to_element_id: u16) -> u16 {
+        let today = Local::now().date_naive();
         let mut offset: u16 = 0;
 
-        self.todo_list.iter().any(|todo_item| {
-            let text = TodoItem::format_description(&todo_item.description);
-            let is_match = todo_item.id == to_element_id;
+        self.get_view()
+            .iter()
+            .map(|index| &self.todo_list[*index])
+            .skip(self.scroll_offset.get())
+            .any(|todo_item| {
+                let is_match = todo_item.id == to_element_id;
 
-            if !is_match {
-                offset += TodoItem::get_line_height(&text);
-            }
+                if !is_match {
+                    offset += TodoItem::get_line_height(&todo_item.format(today, None, None));
+                }
 
-            is_match
-        });
+                is_match
+            });
+
+        offset + self.get_top_margin()
+    }
+}
+
+impl EventHandler for Todo<'_> {
+    fn handle_event(&mut self, event: &Event, _config: &mut Config) {
+        if *event == Event::FileChanged {
+            self.reload();
+        }
+    }
+}
+
+impl Tab for Todo<'_> {
+    fn title(&self) -> String {
+        TITLE.to_owned()
+    }
+
+    fn render(&self, region: Region, config: &Config) {
+        self.region.set(region);
+        self.render_list(config, 0);
+    }
 
-        offset + LIST_TOP_MARGIN
+    fn handle_action(&mut self, action: &Action, config: &mut Config) {
+        self.listen_action(action, config);
+    }
+
+    fn on_focus(&mut self, config: &Config) {
+        self.set_today_view(config.display_todays);
     }
 }
 
@@ -410,69 +1097,130 @@ impl<'a> Todo<'a> {
 mod tests {
     use super::*;
 
+    use crate::storage::MemoryStore;
+
+    #[test]
+    fn test_visible_range_fits() {
+        assert_eq!(get_visible_range(&[1, 1, 1], 0, 2, 10), (0, 3));
+        assert_eq!(get_visible_range(&[], 0, 0, 10), (0, 0));
+    }
+
+    #[test]
+    fn test_visible_range_scrolls_down() {
+        let heights = [1, 1, 2, 1, 1, 1];
+
+        assert_eq!(get_visible_range(&heights, 0, 1, 3), (0, 2));
+        assert_eq!(get_visible_range(&heights, 0, 2, 3), (1, 3));
+        assert_eq!(get_visible_range(&heights, 1, 5, 3), (3, 6));
+    }
+
+    #[test]
+    fn test_visible_range_scrolls_up() {
+        let heights = [1, 1, 1, 1, 1];
+
+        assert_eq!(get_visible_range(&heights, 3, 1, 2), (1, 3));
+        assert_eq!(get_visible_range(&heights, 3, 3, 2), (3, 5));
+    }
+
     #[test]
     fn test_generate_intermediate_id() {
-        let todo_list = vec![
-            TodoItem {
-                id: 0,
-                date_created: Utc::now(),
-                date_modified: Utc::now(),
-                status: Status::Todo,
-                description: String::from("Zero"),
-            },
-            TodoItem {
-                id: 2,
-                date_created: Utc::now(),
-                date_modified: Utc::now(),
-                status: Status::Todo,
-                description: String::from("Two"),
-            },
-        ];
+        let todo_list = vec![TodoItem::new(0, "Zero"), TodoItem::new(2, "Two")];
 
         assert_eq!(generate_id(&todo_list), 1);
     }
 
     #[test]
     fn test_generate_starting_id() {
-        let todo_list = vec![
-            TodoItem {
-                id: 1,
-                date_created: Utc::now(),
-                date_modified: Utc::now(),
-                status: Status::Todo,
-                description: String::from("One"),
-            },
-            TodoItem {
-                id: 2,
-                date_created: Utc::now(),
-                date_modified: Utc::now(),
-                status: Status::Todo,
-                description: String::from("Two"),
-            },
-        ];
+        let todo_list = vec![TodoItem::new(1, "One"), TodoItem::new(2, "Two")];
 
         assert_eq!(generate_id(&todo_list), 0);
     }
 
     #[test]
     fn test_generate_ending_id() {
-        let todo_list = vec![
-            TodoItem {
-                id: 0,
-                date_created: Utc::now(),
-                date_modified: Utc::now(),
-                status: Status::Todo,
-                description: String::from("Zero"),
-            },
-            TodoItem {
-                id: 1,
-                date_created: Utc::now(),
-                date_modified: Utc::now(),
-                status: Status::Todo,
-                description: String::from("One"),
-            },
-        ];
+        let todo_list = vec![TodoItem::new(0, "Zero"), TodoItem::new(1, "One")];
 
         assert_eq!(generate_id(&todo_list), 2);
     }
+
+    #[test]
+    fn test_changes_are_written_to_store() {
+        let store = MemoryStore::new(vec![TodoItem::new(0, "Walk")]);
+        let mut todo = Todo::init(store.clone()).unwrap();
+
+        let id = todo.add("Pay rent").id;
+
+        todo.write_insert(id).unwrap();
+        todo.select(id);
+        todo.change_selected_priority(Priority::raise);
+
+        assert_eq!(store.get_todo_list(), todo.todo_list);
+        assert_eq!(store.get_todo_list()[1].priority, Some(Priority::E));
+
+        todo.select(0);
+        todo.remove_selected_todo();
+
+        assert_eq!(store.get_todo_list(), todo.todo_list);
+        assert_eq!(todo.todo_list.len(), 1);
+    }
+
+    #[test]
+    fn test_change_of_removed_todo_reloads() {
+        let store = MemoryStore::new(vec![TodoItem::new(0, "Walk"), TodoItem::new(1, "Pay rent")]);
+        let mut todo = Todo::init(store.clone()).unwrap();
+
+        store.delete(1).unwrap();
+        todo.select(1);
+        todo.change_selected_priority(Priority::raise);
+
+        assert_eq!(todo.todo_list, store.get_todo_list());
+        assert!(todo.take_save_error().is_some());
+        assert_eq!(todo.take_save_error(), None);
+    }
+
+    #[test]
+    fn test_reload_changed_store() {
+        let store = MemoryStore::new(vec![TodoItem::new(0, "Walk")]);
+        let mut todo = Todo::init(store.clone()).unwrap();
+        let list = vec![TodoItem::new(0, "Walk"), TodoItem::new(1, "Pay rent")];
+
+        store.save(&list).unwrap();
+        todo.reload();
+
+        assert_eq!(todo.todo_list, list);
+
+        todo.undo();
+
+        assert_eq!(todo.todo_list.len(), 1);
+        assert_eq!(store.get_todo_list(), todo.todo_list);
+    }
+
+    #[test]
+    fn test_today_view_items() {
+        let today = Local::now().date_naive();
+        let tomorrow = today.succ_opt().unwrap();
+        let mut item = TodoItem::new(0, "Walk");
+
+        item.date_created = Utc::now() - chrono::Duration::days(3);
+
+        assert!(item.is_shown_on(today));
+
+        item.scheduled = Some(tomorrow);
+
+        assert!(!item.is_shown_on(today));
+        assert!(item.is_shown_on(tomorrow));
+
+        item.due = Some(today);
+
+        assert!(item.is_shown_on(today));
+
+        item.set_status(Status::Done);
+
+        assert!(item.is_shown_on(today));
+        assert!(!item.is_shown_on(tomorrow));
+
+        item.set_status(Status::Todo);
+
+        assert_eq!(item.date_completed, None);
+    }
 }
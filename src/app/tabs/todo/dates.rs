@@ -0,0 +1,181 @@
+use chrono::{Datelike, Days, NaiveDate, TimeDelta, Weekday};
+
+pub const DUE_KEY: &str = "due";
+pub const SCHEDULED_KEY: &str = "sched";
+const SCHEDULED_KEY_LONG: &str = "scheduled";
+pub const DATE_FORMAT: &str = "%Y-%m-%d";
+pub const DAY_FORMAT: &str = "%A, %-d %B %Y";
+
+// --- Note ---
+// Weekday is always the next one, so `fri` typed on a Friday means a week later
+fn get_next_weekday(weekday: Weekday, today: NaiveDate) -> Option<NaiveDate> {
+    let days_ahead =
+        (7 + weekday.num_days_from_monday() - today.weekday().num_days_from_monday()) % 7;
+    let days_ahead = if days_ahead == 0 { 7 } else { days_ahead };
+
+    today.checked_add_days(Days::new(days_ahead as u64))
+}
+
+fn get_offset_date(value: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let value = value.strip_prefix('+').unwrap_or(value);
+    let unit = value.chars().last()?;
+    let count = value[..value.len() - unit.len_utf8()].parse::<i64>().ok()?;
+
+    let offset = match unit {
+        'd' => TimeDelta::try_days(count)?,
+        'w' => TimeDelta::try_weeks(count)?,
+        _ => return None,
+    };
+
+    today.checked_add_signed(offset)
+}
+
+pub fn parse_date(value: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let value = value.to_lowercase();
+
+    match value.as_str() {
+        "today" => Some(today),
+        "tomorrow" => today.succ_opt(),
+        "yesterday" => today.pred_opt(),
+        _ => NaiveDate::parse_from_str(&value, DATE_FORMAT)
+            .ok()
+            .or_else(|| {
+                value
+                    .parse::<Weekday>()
+                    .ok()
+                    .and_then(|weekday| get_next_weekday(weekday, today))
+            })
+            .or_else(|| get_offset_date(&value, today)),
+    }
+}
+
+pub fn format_date(date: NaiveDate, today: NaiveDate) -> String {
+    let days = (date - today).num_days();
+
+    match days {
+        0 => String::from("today"),
+        1 => String::from("tomorrow"),
+        -1 => String::from("yesterday"),
+        2..=6 => date.format("%a").to_string(),
+        _ if date.year() == today.year() => date.format("%-d %b").to_string(),
+        _ => date.format("%-d %b %Y").to_string(),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Input {
+    pub description: String,
+    pub due: Option<Option<NaiveDate>>,
+    pub scheduled: Option<Option<NaiveDate>>,
+}
+
+// --- Note ---
+// Dates are `None` when the input leaves them alone and `Some(None)` when it clears them with an
+// empty value like `due:`
+
+pub fn parse_input(input: &str, today: NaiveDate) -> Input {
+    let mut due = None;
+    let mut scheduled = None;
+
+    let words: Vec<&str> = input
+        .split(' ')
+        .filter(|word| {
+            let Some((key, value)) = word.split_once(':') else {
+                return true;
+            };
+
+            let target = match key.to_lowercase().as_str() {
+                DUE_KEY => &mut due,
+                SCHEDULED_KEY | SCHEDULED_KEY_LONG => &mut scheduled,
+                _ => return true,
+            };
+
+            match parse_date(value, today) {
+                _ if value.is_empty() => {
+                    *target = Some(None);
+                    false
+                }
+                Some(date) => {
+                    *target = Some(Some(date));
+                    false
+                }
+                None => true,
+            }
+        })
+        .collect();
+
+    Input {
+        description: words.join(" ").trim().to_owned(),
+        due,
+        scheduled,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, DATE_FORMAT).unwrap()
+    }
+
+    #[test]
+    fn test_parse_date() {
+        // Sunday
+        let today = date("2026-10-18");
+
+        assert_eq!(parse_date("today", today), Some(today));
+        assert_eq!(parse_date("Tomorrow", today), Some(date("2026-10-19")));
+        assert_eq!(parse_date("2026-11-03", today), Some(date("2026-11-03")));
+        assert_eq!(parse_date("fri", today), Some(date("2026-10-23")));
+        assert_eq!(parse_date("sunday", today), Some(date("2026-10-25")));
+        assert_eq!(parse_date("3d", today), Some(date("2026-10-21")));
+        assert_eq!(parse_date("+2w", today), Some(date("2026-11-01")));
+        assert_eq!(parse_date("soon", today), None);
+        assert_eq!(parse_date("999999999d", today), None);
+        assert_eq!(parse_date("99999999999999999w", today), None);
+        assert_eq!(parse_date("-99999999999999999w", today), None);
+        assert_eq!(parse_date("2026-13-01", today), None);
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let today = date("2026-10-18");
+
+        assert_eq!(
+            parse_input("Pay rent due:fri sched:tomorrow", today),
+            Input {
+                description: String::from("Pay rent"),
+                due: Some(Some(date("2026-10-23"))),
+                scheduled: Some(Some(date("2026-10-19"))),
+            }
+        );
+        assert_eq!(
+            parse_input("Pay rent due: sched:", today),
+            Input {
+                description: String::from("Pay rent"),
+                due: Some(None),
+                scheduled: Some(None),
+            }
+        );
+        assert_eq!(
+            parse_input("Read about due:diligence", today),
+            Input {
+                description: String::from("Read about due:diligence"),
+                due: None,
+                scheduled: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_format_date() {
+        let today = date("2026-10-18");
+
+        assert_eq!(format_date(today, today), "today");
+        assert_eq!(format_date(date("2026-10-17"), today), "yesterday");
+        assert_eq!(format_date(date("2026-10-21"), today), "Wed");
+        assert_eq!(format_date(date("2026-11-03"), today), "3 Nov");
+        assert_eq!(format_date(date("2027-01-05"), today), "5 Jan 2027");
+    }
+}
@@ -0,0 +1,92 @@
+const HISTORY_LIMIT: usize = 100;
+
+#[derive(Debug, Clone)]
+pub struct History<T: Clone> {
+    undo_stack: Vec<T>,
+    redo_stack: Vec<T>,
+}
+
+impl<T: Clone> History<T> {
+    pub fn init() -> Self {
+        History {
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, snapshot: T) {
+        if self.undo_stack.len() == HISTORY_LIMIT {
+            self.undo_stack.remove(0);
+        }
+
+        self.undo_stack.push(snapshot);
+        self.redo_stack.clear();
+    }
+
+    pub fn undo(&mut self, current: T) -> Option<T> {
+        let snapshot = self.undo_stack.pop()?;
+
+        self.redo_stack.push(current);
+
+        Some(snapshot)
+    }
+
+    pub fn redo(&mut self, current: T) -> Option<T> {
+        let snapshot = self.redo_stack.pop()?;
+
+        self.undo_stack.push(current);
+
+        Some(snapshot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_undo_redo() {
+        let mut history = History::init();
+
+        history.record(1);
+        history.record(2);
+
+        assert_eq!(history.undo(3), Some(2));
+        assert_eq!(history.undo(2), Some(1));
+        assert_eq!(history.undo(1), None);
+        assert_eq!(history.redo(1), Some(2));
+        assert_eq!(history.redo(2), Some(3));
+        assert_eq!(history.redo(3), None);
+    }
+
+    #[test]
+    fn test_record_clears_redo() {
+        let mut history = History::init();
+
+        history.record(1);
+        history.undo(2);
+        history.record(1);
+
+        assert_eq!(history.redo(5), None);
+    }
+
+    #[test]
+    fn test_history_limit() {
+        let mut history = History::init();
+
+        for snapshot in 0..HISTORY_LIMIT + 5 {
+            history.record(snapshot);
+        }
+
+        let mut current = HISTORY_LIMIT + 5;
+        let mut count = 0;
+
+        while let Some(snapshot) = history.undo(current) {
+            current = snapshot;
+            count += 1;
+        }
+
+        assert_eq!(count, HISTORY_LIMIT);
+        assert_eq!(current, 5);
+    }
+}
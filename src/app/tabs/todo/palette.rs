@@ -0,0 +1,15 @@
+use termion::color::AnsiValue;
+
+const RED: AnsiValue = AnsiValue(1);
+const GREEN: AnsiValue = AnsiValue(2);
+const YELLOW: AnsiValue = AnsiValue(3);
+const BLUE: AnsiValue = AnsiValue(4);
+const MAGENTA: AnsiValue = AnsiValue(5);
+const CYAN: AnsiValue = AnsiValue(6);
+const GREY: AnsiValue = AnsiValue(8);
+
+pub const OVERDUE: AnsiValue = RED;
+pub const DUE_TODAY: AnsiValue = YELLOW;
+pub const PRIORITIES: [AnsiValue; 5] = [RED, YELLOW, GREEN, BLUE, GREY];
+pub const PROJECT: AnsiValue = CYAN;
+pub const CONTEXT: AnsiValue = MAGENTA;
@@ -0,0 +1,131 @@
+use super::palette;
+
+use core::str::FromStr;
+use std::slice::Iter;
+
+const CLEAR_INPUT: &str = "(-)";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Priority {
+    A,
+    B,
+    C,
+    D,
+    E,
+}
+
+impl Priority {
+    pub fn iterate() -> Iter<'static, Priority> {
+        static PRIORITIES: [Priority; 5] = [
+            Priority::A,
+            Priority::B,
+            Priority::C,
+            Priority::D,
+            Priority::E,
+        ];
+        PRIORITIES.iter()
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Priority::A => "A",
+            Priority::B => "B",
+            Priority::C => "C",
+            Priority::D => "D",
+            Priority::E => "E",
+        }
+    }
+
+    pub fn get_color(&self) -> termion::color::AnsiValue {
+        palette::PRIORITIES[*self as usize]
+    }
+
+    pub fn raise(priority: Option<Priority>) -> Option<Priority> {
+        let priorities: Vec<Priority> = Priority::iterate().copied().collect();
+
+        match priority {
+            Some(priority) => {
+                let index = priorities.iter().position(|item| *item == priority)?;
+
+                Some(priorities[index.saturating_sub(1)])
+            }
+            None => priorities.last().copied(),
+        }
+    }
+
+    pub fn lower(priority: Option<Priority>) -> Option<Priority> {
+        let priorities: Vec<Priority> = Priority::iterate().copied().collect();
+        let index = priorities.iter().position(|item| Some(*item) == priority)?;
+
+        priorities.get(index + 1).copied()
+    }
+
+    // --- Note ---
+    // `(-)` is only needed to clear the priority on edit, `None` means the input leaves it alone
+    pub fn parse_input(input: &str) -> (Option<Option<Priority>>, &str) {
+        let input = input.trim_start();
+
+        if let Some(rest) = input.strip_prefix(CLEAR_INPUT) {
+            return (Some(None), rest.trim_start());
+        }
+
+        let priority = input
+            .strip_prefix('(')
+            .and_then(|rest| rest.get(..2))
+            .and_then(|rest| rest.strip_suffix(')'))
+            .and_then(|letter| Priority::from_str(letter).ok());
+
+        match priority {
+            Some(priority) => (Some(Some(priority)), input[3..].trim_start()),
+            None => (None, input),
+        }
+    }
+}
+
+impl FromStr for Priority {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" => Ok(Priority::A),
+            "B" => Ok(Priority::B),
+            "C" => Ok(Priority::C),
+            "D" => Ok(Priority::D),
+            "E" => Ok(Priority::E),
+            _ => Err("No such priority availabe, try using 'A/B/C/D/E'"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(
+            Priority::parse_input("(A) Pay rent"),
+            (Some(Some(Priority::A)), "Pay rent")
+        );
+        assert_eq!(
+            Priority::parse_input("(-) Pay rent"),
+            (Some(None), "Pay rent")
+        );
+        assert_eq!(Priority::parse_input("(e)Pay rent"), (None, "(e)Pay rent"));
+        assert_eq!(
+            Priority::parse_input("(F) Pay rent"),
+            (None, "(F) Pay rent")
+        );
+        assert_eq!(Priority::parse_input("(A"), (None, "(A"));
+    }
+
+    #[test]
+    fn test_raise_and_lower() {
+        assert_eq!(Priority::raise(None), Some(Priority::E));
+        assert_eq!(Priority::raise(Some(Priority::B)), Some(Priority::A));
+        assert_eq!(Priority::raise(Some(Priority::A)), Some(Priority::A));
+        assert_eq!(Priority::lower(Some(Priority::A)), Some(Priority::B));
+        assert_eq!(Priority::lower(Some(Priority::E)), None);
+        assert_eq!(Priority::lower(None), None);
+    }
+}
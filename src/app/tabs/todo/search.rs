@@ -0,0 +1,173 @@
+use super::Todo;
+use crate::app::helper::{Cursor, Print, Screen};
+use crate::config::Config;
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::io::{stdout, Write};
+
+use regex::{Regex, RegexBuilder};
+
+use rustyline::completion::Completer;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::Validator;
+use rustyline::Helper;
+
+const IGNORE_CASE_FLAG: &str = "\\c";
+const MATCH_CASE_FLAG: &str = "\\C";
+const REGEX_FLAG: &str = "\\v";
+
+#[derive(Debug, Clone)]
+pub struct Search {
+    query: String,
+    regex: Regex,
+}
+
+impl Search {
+    pub fn parse(input: &str) -> Result<Option<Search>, String> {
+        let is_regex = input.contains(REGEX_FLAG);
+        let query = [IGNORE_CASE_FLAG, MATCH_CASE_FLAG, REGEX_FLAG]
+            .iter()
+            .fold(input.to_owned(), |query, flag| query.replace(flag, ""));
+
+        if query.is_empty() {
+            return Ok(None);
+        }
+
+        let ignore_case = if input.contains(MATCH_CASE_FLAG) {
+            false
+        } else {
+            input.contains(IGNORE_CASE_FLAG) || !query.chars().any(char::is_uppercase)
+        };
+
+        let pattern = if is_regex {
+            query.clone()
+        } else {
+            regex::escape(&query)
+        };
+
+        let regex = RegexBuilder::new(&pattern)
+            .case_insensitive(ignore_case)
+            .build()
+            .map_err(|_| format!("'{query}' is not a valid regular expression"))?;
+
+        Ok(Some(Search {
+            query: input.to_owned(),
+            regex,
+        }))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.query
+    }
+
+    pub fn is_match(&self, text: &str) -> bool {
+        self.regex.is_match(text)
+    }
+
+    pub fn highlight(&self, text: &str) -> String {
+        self.regex
+            .replace_all(text, |captures: &regex::Captures| {
+                format!(
+                    "{invert}{found}{reset}",
+                    invert = termion::style::Invert,
+                    found = &captures[0],
+                    reset = termion::style::NoInvert,
+                )
+            })
+            .into_owned()
+    }
+}
+
+// --- Note ---
+// Rustyline doesn't let us know when the line changes, but it asks to highlight the line after
+// every key, so the list is rendered again from there. Cursor is saved and restored, because
+// rustyline keeps track of where it left it
+pub struct SearchPreview<'t, 'c> {
+    pub todo: RefCell<Todo<'t>>,
+    pub config: &'c Config<'c>,
+}
+
+impl<'t, 'c> SearchPreview<'t, 'c> {
+    fn render(&self, line: &str) {
+        let mut todo = self.todo.borrow_mut();
+        let (_, y_size) = Screen::get_size();
+
+        let error = match Search::parse(line) {
+            Ok(search) => {
+                todo.search = search;
+                None
+            }
+            Err(err) => Some(err),
+        };
+
+        print!("{}", termion::cursor::Save);
+
+        Screen::clear_rows(todo.get_top_margin(), y_size.saturating_sub(2));
+        Screen::clear_rows(y_size, y_size);
+        Cursor::reset();
+
+        todo.render_list(self.config, 1);
+
+        if let Some(error) = error {
+            Print::status(&error);
+        }
+
+        print!("{}", termion::cursor::Restore);
+
+        stdout().flush().unwrap();
+    }
+}
+
+impl<'t, 'c> Highlighter for SearchPreview<'t, 'c> {
+    fn highlight<'l>(&self, line: &'l str, _: usize) -> Cow<'l, str> {
+        self.render(line);
+
+        Cow::Borrowed(line)
+    }
+
+    fn highlight_char(&self, _: &str, _: usize) -> bool {
+        true
+    }
+}
+
+impl<'t, 'c> Completer for SearchPreview<'t, 'c> {
+    type Candidate = String;
+}
+
+impl<'t, 'c> Hinter for SearchPreview<'t, 'c> {
+    type Hint = String;
+}
+
+impl<'t, 'c> Validator for SearchPreview<'t, 'c> {}
+
+impl<'t, 'c> Helper for SearchPreview<'t, 'c> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_match(query: &str, text: &str) -> bool {
+        Search::parse(query).unwrap().unwrap().is_match(text)
+    }
+
+    #[test]
+    fn test_search_modes() {
+        assert!(is_match("rent", "Pay Rent"));
+        assert!(!is_match("Rent", "Pay rent"));
+        assert!(is_match("Rent\\c", "Pay rent"));
+        assert!(!is_match("\\Crent", "Pay Rent"));
+        assert!(!is_match("p.y", "Pay rent"));
+        assert!(is_match("\\vp.y", "Pay rent"));
+        assert!(is_match("\\v^pay (rent|bills)$", "Pay bills"));
+    }
+
+    #[test]
+    fn test_search_errors() {
+        assert!(Search::parse("").unwrap().is_none());
+        assert!(Search::parse("\\c").unwrap().is_none());
+        assert!(Search::parse("\\v(unclosed").is_err());
+        assert!(Search::parse("(unclosed").unwrap().is_some());
+    }
+}
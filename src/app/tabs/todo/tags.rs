@@ -0,0 +1,113 @@
+use super::palette;
+
+use std::fmt;
+
+const PROJECT_PREFIX: char = '+';
+const CONTEXT_PREFIX: char = '@';
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TagKind {
+    Project,
+    Context,
+}
+
+impl TagKind {
+    pub fn prefix(&self) -> char {
+        match self {
+            TagKind::Project => PROJECT_PREFIX,
+            TagKind::Context => CONTEXT_PREFIX,
+        }
+    }
+
+    pub fn get_color(&self) -> termion::color::AnsiValue {
+        match self {
+            TagKind::Project => palette::PROJECT,
+            TagKind::Context => palette::CONTEXT,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tag {
+    pub kind: TagKind,
+    pub name: String,
+}
+
+impl Tag {
+    pub fn parse(word: &str) -> Option<Tag> {
+        let mut chars = word.chars();
+        let kind = match chars.next()? {
+            PROJECT_PREFIX => TagKind::Project,
+            CONTEXT_PREFIX => TagKind::Context,
+            _ => return None,
+        };
+        let name = chars
+            .as_str()
+            .trim_end_matches(|ch: char| ch.is_ascii_punctuation());
+
+        if name.is_empty() || name.chars().any(char::is_whitespace) {
+            return None;
+        }
+
+        Some(Tag {
+            kind,
+            name: name.to_owned(),
+        })
+    }
+
+    pub fn parse_all(text: &str) -> Vec<Tag> {
+        text.split_whitespace().filter_map(Tag::parse).collect()
+    }
+
+    pub fn matches(&self, other: &Tag) -> bool {
+        self.kind == other.kind && self.name.to_lowercase() == other.name.to_lowercase()
+    }
+
+    pub fn highlight(text: &str) -> String {
+        text.split(' ')
+            .map(|word| match Tag::parse(word) {
+                Some(tag) => format!(
+                    "{fg}{word}{reset}",
+                    fg = termion::color::Fg(tag.kind.get_color()),
+                    reset = termion::color::Fg(termion::color::Reset),
+                ),
+                None => word.to_owned(),
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.kind.prefix(), self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tags() {
+        assert_eq!(
+            Tag::parse_all("Fix +mindr build @work, not 1+1 or a lone + @"),
+            vec![
+                Tag {
+                    kind: TagKind::Project,
+                    name: String::from("mindr")
+                },
+                Tag {
+                    kind: TagKind::Context,
+                    name: String::from("work")
+                },
+            ]
+        );
+        assert!(Tag::parse("+Mindr")
+            .unwrap()
+            .matches(&Tag::parse("+mindr").unwrap()));
+        assert!(!Tag::parse("+home")
+            .unwrap()
+            .matches(&Tag::parse("@home").unwrap()));
+    }
+}
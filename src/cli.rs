@@ -0,0 +1,750 @@
+use crate::app::tabs::todo::{Status, Todo, TodoItem};
+use crate::format::{json, Format, ImportError};
+use crate::storage::{self, Backend};
+
+use std::fmt;
+use std::fs;
+use std::io::{self, stdin, Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+pub const USAGE: &str = "Usage:
+    mindr [--config <file>] [--data <file>] [command]
+
+Commands:
+    mindr                                   Open the todo list
+    mindr add <text>                        Add a new todo, e.g. '(A) Pay rent due:fri sched:tomorrow'
+    mindr list [--status todo|done] [--format plain|json]
+                                            Print todos
+    mindr done <id>                         Mark todo as done
+    mindr rm <id>                           Remove todo
+    mindr edit <id> <text>                  Change todo description, keeping the dates and
+                                            priority it doesn't set, '(-)' and 'due:' clear them
+    mindr export [--format todotxt|json|csv]
+                                            Print todos in another format
+    mindr import [--format todotxt|json|csv] [<file>]
+                                            Add todos from a file or standard input
+    mindr restore [<number>]                List backups or restore one of them
+    mindr help                              Print this message
+
+Options:
+    --config <file>                         Use configuration file, or set MINDR_CONFIG
+    --data <file>                           Use todo list file, or set MINDR_DATA";
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    Usage(String),
+    NotFound(u16),
+    BackupNotFound(usize),
+    Io(String),
+    Import(ImportError),
+    OutputClosed,
+}
+
+impl Error {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::OutputClosed => 0,
+            Error::Usage(_) => 2,
+            Error::NotFound(_) | Error::BackupNotFound(_) | Error::Io(_) | Error::Import(_) => 1,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Usage(message) => write!(f, "{message}\n\n{USAGE}"),
+            Error::NotFound(id) => write!(f, "No todo with id '{id}'"),
+            Error::BackupNotFound(number) => write!(f, "No backup with number '{number}'"),
+            Error::Io(message) => write!(f, "{message}"),
+            Error::Import(err) => write!(f, "Couldn't import todos, {err}"),
+            Error::OutputClosed => write!(f, "Output closed"),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Options {
+    pub config: Option<PathBuf>,
+    pub data: Option<PathBuf>,
+}
+
+impl Options {
+    pub fn parse(args: &[String]) -> Result<(Self, &[String]), Error> {
+        let mut options = Options::default();
+        let mut rest = args;
+
+        while let Some((arg, tail)) = rest.split_first() {
+            let (name, value, tail) = match arg.split_once('=') {
+                Some((name, value)) if name.starts_with("--") => (name, value, tail),
+                _ if arg.starts_with("--") && arg != "--help" => {
+                    let (value, tail) = tail
+                        .split_first()
+                        .ok_or_else(|| Error::Usage(format!("Missing value for '{arg}'")))?;
+
+                    (arg.as_str(), value.as_str(), tail)
+                }
+                _ => break,
+            };
+
+            match name {
+                "--config" => options.config = Some(PathBuf::from(value)),
+                "--data" => options.data = Some(PathBuf::from(value)),
+                _ => return Err(Error::Usage(format!("Unknown option '{name}'"))),
+            }
+
+            rest = tail;
+        }
+
+        Ok((options, rest))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ListFormat {
+    Plain,
+    Json,
+}
+
+impl FromStr for ListFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "plain" => Ok(ListFormat::Plain),
+            "json" => Ok(ListFormat::Json),
+            _ => Err(Error::Usage(format!(
+                "No such format '{s}', try using 'plain/json'"
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Add {
+        description: String,
+    },
+    List {
+        status: Option<Status>,
+        format: ListFormat,
+    },
+    Done {
+        id: u16,
+    },
+    Remove {
+        id: u16,
+    },
+    Edit {
+        id: u16,
+        description: String,
+    },
+    Export {
+        format: Format,
+    },
+    Import {
+        format: Format,
+        file: Option<PathBuf>,
+    },
+    Restore {
+        number: Option<usize>,
+    },
+    Help,
+}
+
+fn parse_id(arg: Option<&String>) -> Result<u16, Error> {
+    let arg = arg.ok_or_else(|| Error::Usage(String::from("Missing todo id")))?;
+
+    arg.parse::<u16>()
+        .map_err(|_| Error::Usage(format!("'{arg}' is not a valid todo id")))
+}
+
+fn parse_description(args: &[String]) -> Result<String, Error> {
+    let description = args.join(" ");
+
+    if description.trim().is_empty() {
+        return Err(Error::Usage(String::from("Todo text can not be empty")));
+    }
+
+    Ok(description.trim().to_owned())
+}
+
+fn parse_format(s: &str) -> Result<Format, Error> {
+    Format::from_str(s).map_err(|err| Error::Usage(format!("No such format '{s}': {err}")))
+}
+
+fn parse_format_options(args: &[String]) -> Result<(Format, Vec<&String>), Error> {
+    let mut format = Format::TodoTxt;
+    let mut rest = Vec::new();
+    let mut options = args.iter();
+
+    while let Some(option) = options.next() {
+        match option.as_str() {
+            "--format" => {
+                let value = options
+                    .next()
+                    .ok_or_else(|| Error::Usage(format!("Missing value for '{option}'")))?;
+
+                format = parse_format(value)?;
+            }
+            _ if option.starts_with("--") => {
+                return Err(Error::Usage(format!("Unknown option '{option}'")))
+            }
+            _ => rest.push(option),
+        }
+    }
+
+    Ok((format, rest))
+}
+
+// --- Note ---
+// Output closed by the reader, e.g. `mindr list | head -1`, is not an error
+fn output_error(err: io::Error) -> Error {
+    match err.kind() {
+        io::ErrorKind::BrokenPipe => Error::OutputClosed,
+        _ => Error::Io(format!("Couldn't write to output: {err}")),
+    }
+}
+
+fn save_error(err: io::Error) -> Error {
+    Error::Io(format!("Couldn't save todo list: {err}"))
+}
+
+fn read_input(file: Option<&Path>) -> Result<String, Error> {
+    let mut text = String::new();
+
+    match file {
+        Some(file) => {
+            text = fs::read_to_string(file)
+                .map_err(|err| Error::Io(format!("Couldn't read '{}': {err}", file.display())))?
+        }
+        None => {
+            stdin()
+                .read_to_string(&mut text)
+                .map_err(|err| Error::Io(format!("Couldn't read standard input: {err}")))?;
+        }
+    }
+
+    Ok(text)
+}
+
+fn parse_status(s: &str) -> Result<Status, Error> {
+    Status::from_str(s)
+        .map_err(|_| Error::Usage(format!("No such status '{s}', try using 'todo/done'")))
+}
+
+fn print_plain(out: &mut impl Write, items: &[&TodoItem]) -> Result<(), Error> {
+    for item in items {
+        writeln!(
+            out,
+            "{id}\t{status}\t{description}",
+            id = item.id(),
+            status = item.status.as_str().to_lowercase(),
+            description = item.get_input()
+        )
+        .map_err(output_error)?;
+    }
+
+    Ok(())
+}
+
+fn print_json(out: &mut impl Write, items: &[&TodoItem]) -> Result<(), Error> {
+    let items: Vec<serde_json::Value> = items.iter().map(|item| json::format_item(item)).collect();
+
+    let output = serde_json::to_string_pretty(&items).expect("Couldn't serialize todo list");
+
+    writeln!(out, "{output}").map_err(output_error)
+}
+
+fn restore(path: &Path, number: Option<usize>, out: &mut impl Write) -> Result<(), Error> {
+    let backups = storage::list_backups(path)
+        .map_err(|err| Error::Io(format!("Couldn't read backups: {err}")))?;
+
+    let number = match number {
+        Some(number) => number,
+        None => {
+            if backups.is_empty() {
+                writeln!(out, "No backups of '{}' found", path.display()).map_err(output_error)?;
+                return Ok(());
+            }
+
+            for (index, backup) in backups.iter().enumerate() {
+                let name = backup.file_name().unwrap_or_default().to_string_lossy();
+
+                writeln!(out, "{number}\t{name}", number = index + 1).map_err(output_error)?;
+            }
+
+            write!(out, "Restore backup number (empty to cancel): ").map_err(output_error)?;
+            out.flush().map_err(output_error)?;
+
+            let mut input = String::new();
+
+            stdin()
+                .read_line(&mut input)
+                .map_err(|err| Error::Io(format!("Couldn't read the answer: {err}")))?;
+
+            if input.trim().is_empty() {
+                return Ok(());
+            }
+
+            input.trim().parse::<usize>().map_err(|_| {
+                Error::Usage(format!("'{}' is not a valid backup number", input.trim()))
+            })?
+        }
+    };
+
+    let backup = number
+        .checked_sub(1)
+        .and_then(|index| backups.get(index))
+        .ok_or(Error::BackupNotFound(number))?;
+
+    storage::restore(path, backup)
+        .map_err(|err| Error::Io(format!("Couldn't restore backup: {err}")))?;
+
+    writeln!(
+        out,
+        "Restored '{}'",
+        backup.file_name().unwrap_or_default().to_string_lossy()
+    )
+    .map_err(output_error)?;
+
+    Ok(())
+}
+
+impl Command {
+    pub fn parse(args: &[String]) -> Result<Option<Self>, Error> {
+        let (name, rest) = match args.split_first() {
+            Some((name, rest)) => (name.as_str(), rest),
+            None => return Ok(None),
+        };
+
+        let command = match name {
+            "add" => Command::Add {
+                description: parse_description(rest)?,
+            },
+            "list" => {
+                let mut status = None;
+                let mut format = ListFormat::Plain;
+                let mut options = rest.iter();
+
+                while let Some(option) = options.next() {
+                    let value = options
+                        .next()
+                        .ok_or_else(|| Error::Usage(format!("Missing value for '{option}'")))?;
+
+                    match option.as_str() {
+                        "--status" => status = Some(parse_status(value)?),
+                        "--format" => format = ListFormat::from_str(value)?,
+                        _ => return Err(Error::Usage(format!("Unknown option '{option}'"))),
+                    }
+                }
+
+                Command::List { status, format }
+            }
+            "done" => Command::Done {
+                id: parse_id(rest.first())?,
+            },
+            "rm" => Command::Remove {
+                id: parse_id(rest.first())?,
+            },
+            "edit" => Command::Edit {
+                id: parse_id(rest.first())?,
+                description: parse_description(rest.get(1..).unwrap_or_default())?,
+            },
+            "export" => {
+                let (format, rest) = parse_format_options(rest)?;
+
+                if let Some(arg) = rest.first() {
+                    return Err(Error::Usage(format!("Unexpected argument '{arg}'")));
+                }
+
+                Command::Export { format }
+            }
+            "import" => {
+                let (format, rest) = parse_format_options(rest)?;
+
+                if let Some(arg) = rest.get(1) {
+                    return Err(Error::Usage(format!("Unexpected argument '{arg}'")));
+                }
+
+                Command::Import {
+                    format,
+                    file: rest
+                        .first()
+                        .filter(|file| file.as_str() != "-")
+                        .map(PathBuf::from),
+                }
+            }
+            "restore" => Command::Restore {
+                number: rest
+                    .first()
+                    .map(|arg| {
+                        arg.parse::<usize>().map_err(|_| {
+                            Error::Usage(format!("'{arg}' is not a valid backup number"))
+                        })
+                    })
+                    .transpose()?,
+            },
+            "help" | "--help" | "-h" => Command::Help,
+            _ => return Err(Error::Usage(format!("Unknown command '{name}'"))),
+        };
+
+        Ok(Some(command))
+    }
+
+    pub fn execute(
+        self,
+        backend: &Backend,
+        path: &Path,
+        out: &mut impl Write,
+    ) -> Result<(), Error> {
+        if let Command::Restore { number } = self {
+            return restore(&backend.get_path(path), number, out);
+        }
+
+        let mut todo = Todo::init(storage::open(backend, path).map_err(Error::Io)?)
+            .map_err(|err| Error::Io(format!("Couldn't read todo list: {err}")))?;
+
+        for error in todo.get_load_errors() {
+            eprintln!(
+                "Skipped malformed {error}, moved to '{}'",
+                storage::get_rejected_path(path).display()
+            );
+        }
+
+        match self {
+            Command::Add { description } => {
+                let id = todo.add(&description).id();
+
+                todo.write_insert(id).map_err(save_error)?;
+                writeln!(out, "{id}").map_err(output_error)?;
+            }
+            Command::List { status, format } => {
+                let items: Vec<&TodoItem> = todo
+                    .todo_list
+                    .iter()
+                    .filter(|item| status.as_ref().is_none_or(|status| item.status == *status))
+                    .collect();
+
+                match format {
+                    ListFormat::Plain => print_plain(out, &items)?,
+                    ListFormat::Json => print_json(out, &items)?,
+                }
+            }
+            Command::Done { id } => {
+                let item = todo.find_mut(id).ok_or(Error::NotFound(id))?;
+
+                if item.status != Status::Done {
+                    item.set_status(Status::Done);
+                    todo.write_update(id).map_err(save_error)?;
+                }
+            }
+            Command::Remove { id } => {
+                todo.remove(id).ok_or(Error::NotFound(id))?;
+                todo.write_delete(id).map_err(save_error)?;
+            }
+            Command::Edit { id, description } => {
+                let item = todo.find_mut(id).ok_or(Error::NotFound(id))?;
+
+                item.set_description(&description);
+                todo.write_update(id).map_err(save_error)?;
+            }
+            Command::Export { format } => {
+                write!(out, "{}", todo.export(&format)).map_err(output_error)?;
+            }
+            Command::Import { format, file } => {
+                let text = read_input(file.as_deref())?;
+                let count = todo.import(&format, &text).map_err(Error::Import)?;
+
+                todo.write().map_err(save_error)?;
+                writeln!(out, "Imported {count} todo(s)").map_err(output_error)?;
+            }
+            Command::Restore { .. } => unreachable!(),
+            Command::Help => {
+                writeln!(out, "{USAGE}").map_err(output_error)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::get_temp_path;
+
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    fn run(command: &[&str], path: &Path) -> Result<String, Error> {
+        run_with(&Backend::Text, command, path)
+    }
+
+    fn run_with(backend: &Backend, command: &[&str], path: &Path) -> Result<String, Error> {
+        let mut out = Vec::new();
+
+        Command::parse(&args(command))?
+            .unwrap()
+            .execute(backend, path, &mut out)?;
+
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(Command::parse(&[]), Ok(None));
+        assert_eq!(
+            Command::parse(&args(&["add", "Buy", "milk"])),
+            Ok(Some(Command::Add {
+                description: String::from("Buy milk")
+            }))
+        );
+        assert_eq!(
+            Command::parse(&args(&["list", "--format", "json", "--status", "done"])),
+            Ok(Some(Command::List {
+                status: Some(Status::Done),
+                format: ListFormat::Json
+            }))
+        );
+        assert_eq!(
+            Command::parse(&args(&["edit", "3", "New text"])),
+            Ok(Some(Command::Edit {
+                id: 3,
+                description: String::from("New text")
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let arguments = args(&["--config", "/tmp/a.conf", "--data=/tmp/b.txt", "list"]);
+        let (options, rest) = Options::parse(&arguments).unwrap();
+
+        assert_eq!(options.config, Some(PathBuf::from("/tmp/a.conf")));
+        assert_eq!(options.data, Some(PathBuf::from("/tmp/b.txt")));
+        assert_eq!(rest, &args(&["list"])[..]);
+
+        let arguments = args(&["add", "--data", "x"]);
+        let (options, rest) = Options::parse(&arguments).unwrap();
+
+        assert_eq!(options, Options::default());
+        assert_eq!(rest.len(), 3);
+
+        assert!(Options::parse(&args(&["--data"])).is_err());
+        assert!(Options::parse(&args(&["--unknown", "x"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let errors = [
+            args(&["add"]),
+            args(&["done", "abc"]),
+            args(&["rm"]),
+            args(&["list", "--status"]),
+            args(&["list", "--format", "xml"]),
+            args(&["unknown"]),
+        ];
+
+        for error in errors {
+            assert_eq!(Command::parse(&error).unwrap_err().exit_code(), 2);
+        }
+    }
+
+    #[test]
+    fn test_execute_commands() {
+        let path = get_temp_path("cli_execute");
+
+        assert_eq!(run(&["add", "First"], &path), Ok(String::from("0\n")));
+        assert_eq!(run(&["add", "Second"], &path), Ok(String::from("1\n")));
+
+        run(&["done", "0"], &path).unwrap();
+        run(&["edit", "1", "Second edited"], &path).unwrap();
+
+        assert_eq!(
+            run(&["list"], &path),
+            Ok(String::from("0\tdone\tFirst\n1\ttodo\tSecond edited\n"))
+        );
+        assert_eq!(
+            run(&["list", "--status", "todo"], &path),
+            Ok(String::from("1\ttodo\tSecond edited\n"))
+        );
+
+        run(&["rm", "0"], &path).unwrap();
+
+        assert_eq!(run(&["rm", "0"], &path), Err(Error::NotFound(0)));
+        assert_eq!(
+            run(&["list"], &path),
+            Ok(String::from("1\ttodo\tSecond edited\n"))
+        );
+    }
+
+    #[test]
+    fn test_edit_keeps_attributes() {
+        let path = get_temp_path("cli_edit");
+
+        run(
+            &["add", "(A) Pay rent due:2026-11-03 sched:2026-11-01"],
+            &path,
+        )
+        .unwrap();
+        run(&["edit", "0", "Pay the rent"], &path).unwrap();
+
+        assert_eq!(
+            run(&["list"], &path),
+            Ok(String::from(
+                "0\ttodo\t(A) Pay the rent due:2026-11-03 sched:2026-11-01\n"
+            ))
+        );
+
+        run(&["edit", "0", "(C) Pay the rent due:2026-11-04"], &path).unwrap();
+
+        assert_eq!(
+            run(&["list"], &path),
+            Ok(String::from(
+                "0\ttodo\t(C) Pay the rent due:2026-11-04 sched:2026-11-01\n"
+            ))
+        );
+
+        run(&["edit", "0", "(-) Pay the rent due: sched:"], &path).unwrap();
+
+        assert_eq!(
+            run(&["list"], &path),
+            Ok(String::from("0\ttodo\tPay the rent\n"))
+        );
+    }
+
+    #[test]
+    fn test_import_export_todotxt() {
+        let path = get_temp_path("cli_todotxt");
+        let file = path.with_file_name("import.txt");
+        let lines =
+            "(A) 2026-10-01 Call mom +family due:2026-10-20\nx 2026-10-18 2026-10-02 Walk\n";
+
+        fs::write(&file, lines).unwrap();
+
+        assert_eq!(
+            run(
+                &["import", "--format", "todotxt", file.to_str().unwrap()],
+                &path
+            ),
+            Ok(String::from("Imported 2 todo(s)\n"))
+        );
+        assert_eq!(run(&["export"], &path), Ok(String::from(lines)));
+
+        fs::write(&file, "Walk due:never").unwrap();
+
+        assert!(matches!(
+            run(&["import", file.to_str().unwrap()], &path),
+            Err(Error::Import(_))
+        ));
+        assert!(matches!(
+            Command::parse(&args(&["export", "--format", "yaml"])),
+            Err(Error::Usage(_))
+        ));
+    }
+
+    #[test]
+    fn test_import_export_json_csv() {
+        let path = get_temp_path("cli_export");
+
+        run(&["add", "(B) Pay \"rent\", bills due:2026-10-20"], &path).unwrap();
+        run(&["add", "Walk"], &path).unwrap();
+        run(&["done", "1"], &path).unwrap();
+
+        let list = run(&["list", "--format", "json"], &path).unwrap();
+
+        for format in ["json", "csv"] {
+            let import_path = get_temp_path(&format!("cli_import_{format}"));
+            let file = path.with_file_name(format!("export.{format}"));
+
+            fs::write(&file, run(&["export", "--format", format], &path).unwrap()).unwrap();
+
+            assert_eq!(
+                run(
+                    &["import", "--format", format, file.to_str().unwrap()],
+                    &import_path
+                ),
+                Ok(String::from("Imported 2 todo(s)\n"))
+            );
+            assert_eq!(
+                run(&["list", "--format", "json"], &import_path),
+                Ok(list.clone())
+            );
+        }
+    }
+
+    #[test]
+    fn test_restore_backup() {
+        let path = get_temp_path("cli_restore");
+
+        run(&["add", "First"], &path).unwrap();
+        run(&["add", "Second"], &path).unwrap();
+
+        assert_eq!(run(&["restore", "5"], &path), Err(Error::BackupNotFound(5)));
+
+        run(&["restore", "1"], &path).unwrap();
+
+        assert_eq!(run(&["list"], &path), Ok(String::from("0\ttodo\tFirst\n")));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_restore_sqlite_backup() {
+        let path = get_temp_path("cli_restore_sqlite");
+        let run = |command: &[&str]| run_with(&Backend::Sqlite, command, &path);
+
+        run(&["add", "First"]).unwrap();
+        run(&["add", "Second"]).unwrap();
+        run(&["restore", "1"]).unwrap();
+
+        assert_eq!(run(&["list"]), Ok(String::from("0\ttodo\tFirst\n")));
+    }
+
+    struct ClosedOutput;
+
+    impl Write for ClosedOutput {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(io::Error::from(io::ErrorKind::BrokenPipe))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_closed_output() {
+        let path = get_temp_path("cli_closed_output");
+
+        run(&["add", "First"], &path).unwrap();
+
+        let result = Command::parse(&args(&["list"])).unwrap().unwrap().execute(
+            &Backend::Text,
+            &path,
+            &mut ClosedOutput,
+        );
+
+        assert_eq!(result, Err(Error::OutputClosed));
+        assert_eq!(Error::OutputClosed.exit_code(), 0);
+    }
+
+    #[test]
+    fn test_list_json() {
+        let path = get_temp_path("cli_json");
+
+        run(&["add", "Quote \"me\""], &path).unwrap();
+
+        let output = run(&["list", "--format", "json"], &path).unwrap();
+        let items: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(items[0]["id"], 0);
+        assert_eq!(items[0]["status"], "todo");
+        assert_eq!(items[0]["description"], "Quote \"me\"");
+    }
+}
@@ -1,13 +1,20 @@
+pub mod keys;
+
 use configparser::ini;
 use std::str::FromStr;
 
 use crate::app::selection::Selection;
+use crate::storage::Backend;
+use keys::Binding;
 
-use std::fs::{self, File};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
 use std::path::Path;
 use std::process;
 use std::slice::Iter;
 
+use termion::event::Key;
+
 // For the future:
 // TODO: Go through methods and decide which should be public
 // TODO: Make stronger typing for key values rather than just String
@@ -18,10 +25,8 @@ use std::slice::Iter;
 // TODO: `read_ini` and `write_ini` could be improved with iterators but it takes some research
 // on how to implement access struct fields using string and get it's fields as string without
 // writing to much code
-// TODO: Improve config key mapping from char to string to be able to use Enter and such, or first
-// try '\n' character
 
-fn read_ini(path: &Path) -> Result<Config, String> {
+fn read_ini(path: &Path) -> Result<Config<'_>, String> {
     let mut ini_config = ini::Ini::new();
 
     ini_config
@@ -58,7 +63,7 @@ fn read_ini(path: &Path) -> Result<Config, String> {
     let hide_menu_timeout = match hide_menu_timeout {
         n if n > 60000 => {
             eprintln!("The value of 'hide_menu_timeout' can not be greater than 60000, 'hide_menu_timeout' will be set to default '500'");
-            500 as u16
+            500
         }
         _ => hide_menu_timeout as u16,
     };
@@ -74,50 +79,98 @@ fn read_ini(path: &Path) -> Result<Config, String> {
         Selection::Brackets
     });
 
-    let mut key_mapping: Vec<(Action, char)> = vec![];
+    let priority_colors = ini_config
+        .getbool("style", "priority_colors")?
+        .unwrap_or_else(|| {
+           eprintln!("Couldn't get 'priority_colors' value: Not a boolean. 'priority_colors' will be set to default 'true'");
+           true
+        });
 
-    for (index, action) in Action::iterate().enumerate() {
-        let key = ini_config
-            .get("key_mapping", action.as_str())
-            .unwrap_or_else(|| {
-                let default_config = Config::default();
+    let storage_backend = ini_config.get("storage", "backend").unwrap_or_else(|| {
+        eprintln!(
+            "Couldn't get 'backend' value: Not a string. 'backend' will be set to default 'text'"
+        );
+        String::from("text")
+    });
+    let storage_backend = Backend::from_str(&storage_backend).unwrap_or_else(|err| {
+        eprintln!("Couldn't get 'backend' storage: {err}.");
+        Backend::Text
+    });
 
-                let (_, default_key) = default_config
-                    .key_mapping
-                    .iter()
-                    .find(|(default_action, _)| default_action.as_str() == action.as_str())
-                    .expect("Couldn't find an action");
+    let default_config = Config::default();
+    let mut key_mapping: Vec<(Action, Vec<Binding>)> = vec![];
 
+    for action in Action::iterate() {
+        let default_bindings = Action::get_bindings(&default_config.key_mapping, action);
+        let bindings = ini_config
+            .get("key_mapping", action.as_str())
+            .ok_or_else(|| String::from("Not a string"))
+            .and_then(|value| keys::parse_bindings(&value))
+            .unwrap_or_else(|err| {
                 eprintln!(
-                    "Couldn't get '{:?}' action key. '{:?}' will be set to default {:?}",
-                    action, action, &default_key
+                    "Couldn't get '{action}' action key: {err}. '{action}' will be set to default '{default}'",
+                    action = action.as_str(),
+                    default = keys::format_bindings(default_bindings),
                 );
 
-                default_key.to_string()
+                default_bindings.to_vec()
             });
 
-        let mapping = (
-            action.clone(),
-            key.chars()
-                .next()
-                .expect("Some configuration key mapping has no key value"),
-        );
-
-        key_mapping.insert(index, mapping);
+        key_mapping.push((action.clone(), bindings));
     }
 
-    Ok(Config {
+    let config = Config {
         auto_hide_menu,
         display_todays,
         remind_unfinished,
         hide_menu_timeout,
         selection_style,
+        priority_colors,
+        storage_backend,
         key_mapping,
-        path: &path,
-    })
+        path,
+    };
+
+    append_missing_keys(&ini_config, &config, path).ok();
+
+    Ok(config)
 }
 
-fn write_ini(config: &Config, path: &Path) {
+// --- Note ---
+// Options which are added after the file was written are appended to it with their defaults, so
+// they are reported only once. The rest of the file is left as it is, comments included. Repeated
+// sections are merged on load. The file may be read-only, then they are reported every time
+fn append_missing_keys(ini_config: &ini::Ini, config: &Config, path: &Path) -> io::Result<()> {
+    let full_config = to_ini(config);
+    let mut sections: Vec<_> = full_config.get_map_ref().iter().collect();
+    let mut missing = String::new();
+
+    sections.sort_by_key(|(section, _)| section.as_str());
+
+    for (section, keys) in sections {
+        let mut keys: Vec<String> = keys
+            .iter()
+            .filter(|(key, _)| ini_config.get(section, key).is_none())
+            .map(|(key, value)| format!("{key}={}\n", value.as_deref().unwrap_or_default()))
+            .collect();
+
+        if !keys.is_empty() {
+            keys.sort();
+            missing.push_str(&format!("\n[{section}]\n{}", keys.concat()));
+        }
+    }
+
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    OpenOptions::new()
+        .append(true)
+        .open(path)?
+        .write_all(missing.as_bytes())
+}
+
+fn to_ini(config: &Config) -> ini::Ini {
     let mut ini_config = ini::Ini::new();
 
     ini_config.set(
@@ -145,12 +198,26 @@ fn write_ini(config: &Config, path: &Path) {
         "selection_style",
         Some(config.selection_style.as_str()),
     );
+    ini_config.set(
+        "style",
+        "priority_colors",
+        Some(config.priority_colors.to_string()),
+    );
+    ini_config.setstr("storage", "backend", Some(config.storage_backend.as_str()));
 
-    for (action, key) in &config.key_mapping {
-        ini_config.setstr("key_mapping", action.as_str(), Some(&key.to_string()));
+    for (action, bindings) in &config.key_mapping {
+        ini_config.setstr(
+            "key_mapping",
+            action.as_str(),
+            Some(&keys::format_bindings(bindings)),
+        );
     }
 
-    ini_config.write(path).unwrap_or_else(|err| {
+    ini_config
+}
+
+fn write_ini(config: &Config, path: &Path) {
+    to_ini(config).write(path).unwrap_or_else(|err| {
         panic!("Couldn't save configuration: {err}");
     })
 }
@@ -167,10 +234,25 @@ pub enum Action {
     AddTodo,
     RemoveTodo,
     EditTodo,
+    Undo,
+    Redo,
+    MoveUp,
+    MoveDown,
+    PageUp,
+    PageDown,
+    Top,
+    Bottom,
+    Sort,
+    RaisePriority,
+    LowerPriority,
+    Filter,
+    Search,
+    NextMatch,
+    PrevMatch,
 }
 
 impl Action {
-    fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &'static str {
         match self {
             Action::Up => "up",
             Action::Down => "down",
@@ -181,25 +263,26 @@ impl Action {
             Action::AddTodo => "add_todo",
             Action::RemoveTodo => "remove_todo",
             Action::EditTodo => "edit_todo",
+            Action::Undo => "undo",
+            Action::Redo => "redo",
+            Action::MoveUp => "move_up",
+            Action::MoveDown => "move_down",
+            Action::PageUp => "page_up",
+            Action::PageDown => "page_down",
+            Action::Top => "top",
+            Action::Bottom => "bottom",
+            Action::Sort => "sort",
+            Action::RaisePriority => "raise_priority",
+            Action::LowerPriority => "lower_priority",
+            Action::Filter => "filter",
+            Action::Search => "search",
+            Action::NextMatch => "next_match",
+            Action::PrevMatch => "prev_match",
         }
     }
 
-    pub fn as_char(&self, key_mapping: &Vec<(Self, char)>) -> char {
-        match self {
-            Action::Up => Action::get_action_char(key_mapping, Action::Up),
-            Action::Down => Action::get_action_char(key_mapping, Action::Down),
-            Action::PrevMenu => Action::get_action_char(key_mapping, Action::PrevMenu),
-            Action::NextMenu => Action::get_action_char(key_mapping, Action::NextMenu),
-            Action::Mark => Action::get_action_char(key_mapping, Action::Mark),
-            Action::Quit => Action::get_action_char(key_mapping, Action::Quit),
-            Action::AddTodo => Action::get_action_char(key_mapping, Action::AddTodo),
-            Action::RemoveTodo => Action::get_action_char(key_mapping, Action::RemoveTodo),
-            Action::EditTodo => Action::get_action_char(key_mapping, Action::EditTodo),
-        }
-    }
-
-    fn iterate() -> Iter<'static, Action> {
-        static ACTIONS: [Action; 9] = [
+    pub fn iterate() -> Iter<'static, Action> {
+        static ACTIONS: [Action; 24] = [
             Action::Up,
             Action::Down,
             Action::PrevMenu,
@@ -209,23 +292,54 @@ impl Action {
             Action::AddTodo,
             Action::RemoveTodo,
             Action::EditTodo,
+            Action::Undo,
+            Action::Redo,
+            Action::MoveUp,
+            Action::MoveDown,
+            Action::PageUp,
+            Action::PageDown,
+            Action::Top,
+            Action::Bottom,
+            Action::Sort,
+            Action::RaisePriority,
+            Action::LowerPriority,
+            Action::Filter,
+            Action::Search,
+            Action::NextMatch,
+            Action::PrevMatch,
         ];
         ACTIONS.iter()
     }
 
-    // TODO: figure out or rename
-    pub fn get_action_char(key_mapping: &Vec<(Self, char)>, action: Action) -> char {
-        let (_, key) = key_mapping
+    pub fn get_bindings<'k>(
+        key_mapping: &'k [(Self, Vec<Binding>)],
+        action: &Action,
+    ) -> &'k [Binding] {
+        let (_, bindings) = key_mapping
             .iter()
-            .find(|(map_action, _)| *map_action == action)
+            .find(|(map_action, _)| map_action == action)
             .expect("No such action exist");
 
-        // Key::Char(*key)
-        *key
+        bindings
+    }
+
+    pub fn find(key_mapping: &[(Self, Vec<Binding>)], keys: &[Key]) -> Option<Action> {
+        key_mapping
+            .iter()
+            .find(|(_, bindings)| bindings.iter().any(|binding| binding.get_keys() == keys))
+            .map(|(action, _)| action.clone())
+    }
+
+    pub fn is_prefix(key_mapping: &[(Self, Vec<Binding>)], keys: &[Key]) -> bool {
+        key_mapping.iter().any(|(_, bindings)| {
+            bindings.iter().any(|binding| {
+                binding.get_keys().len() > keys.len() && binding.get_keys().starts_with(keys)
+            })
+        })
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Config<'a> {
     pub path: &'a Path,
     pub display_todays: bool,
@@ -233,7 +347,9 @@ pub struct Config<'a> {
     pub auto_hide_menu: bool,
     pub hide_menu_timeout: u16,
     pub selection_style: Selection,
-    pub key_mapping: Vec<(Action, char)>,
+    pub priority_colors: bool,
+    pub storage_backend: Backend,
+    pub key_mapping: Vec<(Action, Vec<Binding>)>,
 }
 
 impl<'a> Config<'a> {
@@ -253,12 +369,10 @@ impl<'a> Config<'a> {
             return config;
         }
 
-        let config = read_ini(&path).unwrap_or_else(|err| {
+        read_ini(path).unwrap_or_else(|err| {
             eprintln!("Couldn't read configuration file: {err}");
             process::exit(1);
-        });
-
-        config
+        })
     }
 
     fn set_path(&mut self, path: &'a Path) {
@@ -266,23 +380,45 @@ impl<'a> Config<'a> {
     }
 
     pub fn save(&self) {
-        write_ini(&self, self.path);
+        write_ini(self, self.path);
     }
 }
 
 impl<'a> Default for Config<'a> {
     fn default() -> Self {
-        let key_mapping = vec![
-            (Action::Up, 'k'),
-            (Action::Down, 'j'),
-            (Action::PrevMenu, 'h'),
-            (Action::NextMenu, 'l'),
-            (Action::Mark, 'f'),
-            (Action::Quit, 'q'),
-            (Action::AddTodo, 'a'),
-            (Action::RemoveTodo, 'd'),
-            (Action::EditTodo, 'e'),
-        ];
+        let key_mapping = [
+            (Action::Up, "k <Up>"),
+            (Action::Down, "j <Down>"),
+            (Action::PrevMenu, "h <Left>"),
+            (Action::NextMenu, "l <Right>"),
+            (Action::Mark, "f"),
+            (Action::Quit, "q"),
+            (Action::AddTodo, "a"),
+            (Action::RemoveTodo, "d"),
+            (Action::EditTodo, "e"),
+            (Action::Undo, "u"),
+            (Action::Redo, "r <C-r>"),
+            (Action::MoveUp, "K"),
+            (Action::MoveDown, "J"),
+            (Action::PageUp, "[ <PageUp>"),
+            (Action::PageDown, "] <PageDown>"),
+            (Action::Top, "gg <Home>"),
+            (Action::Bottom, "G <End>"),
+            (Action::Sort, "s"),
+            (Action::RaisePriority, "+"),
+            (Action::LowerPriority, "-"),
+            (Action::Filter, "t"),
+            (Action::Search, "/"),
+            (Action::NextMatch, "n"),
+            (Action::PrevMatch, "N"),
+        ]
+        .map(|(action, bindings)| {
+            let bindings =
+                keys::parse_bindings(bindings).expect("Couldn't parse default key mapping");
+
+            (action, bindings)
+        })
+        .to_vec();
 
         Config {
             display_todays: true,
@@ -290,6 +426,8 @@ impl<'a> Default for Config<'a> {
             auto_hide_menu: false,
             hide_menu_timeout: 500,
             selection_style: Selection::Brackets,
+            priority_colors: true,
+            storage_backend: Backend::Text,
             key_mapping,
             path: Path::new(""),
         }
@@ -0,0 +1,190 @@
+use core::fmt;
+use core::str::FromStr;
+
+use termion::event::Key;
+
+const NAMED_KEYS: [(&str, Key); 17] = [
+    ("Enter", Key::Char('\n')),
+    ("Space", Key::Char(' ')),
+    ("Tab", Key::Char('\t')),
+    ("S-Tab", Key::BackTab),
+    ("lt", Key::Char('<')),
+    ("Esc", Key::Esc),
+    ("BS", Key::Backspace),
+    ("Del", Key::Delete),
+    ("Insert", Key::Insert),
+    ("Up", Key::Up),
+    ("Down", Key::Down),
+    ("Left", Key::Left),
+    ("Right", Key::Right),
+    ("Home", Key::Home),
+    ("End", Key::End),
+    ("PageUp", Key::PageUp),
+    ("PageDown", Key::PageDown),
+];
+const MAX_FUNCTION_KEY: u8 = 12;
+
+fn parse_name(name: &str) -> Option<Key> {
+    if let Some((_, key)) = NAMED_KEYS
+        .iter()
+        .find(|(key_name, _)| key_name.eq_ignore_ascii_case(name))
+    {
+        return Some(*key);
+    }
+
+    let mut chars = name.chars();
+
+    match (chars.next(), chars.next(), chars.next(), chars.next()) {
+        (Some('C' | 'c'), Some('-'), Some(ch), None) => Some(Key::Ctrl(ch.to_ascii_lowercase())),
+        (Some('A' | 'a' | 'M' | 'm'), Some('-'), Some(ch), None) => Some(Key::Alt(ch)),
+        (Some('F' | 'f'), ..) => name[1..]
+            .parse::<u8>()
+            .ok()
+            .filter(|number| (1..=MAX_FUNCTION_KEY).contains(number))
+            .map(Key::F),
+        _ => None,
+    }
+}
+
+fn format_key(key: &Key) -> String {
+    if let Some((name, _)) = NAMED_KEYS.iter().find(|(_, named_key)| named_key == key) {
+        return format!("<{name}>");
+    }
+
+    match key {
+        Key::Char(ch) => ch.to_string(),
+        Key::Ctrl(ch) => format!("<C-{ch}>"),
+        Key::Alt(ch) => format!("<A-{ch}>"),
+        Key::F(number) => format!("<F{number}>"),
+        _ => String::from("<?>"),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Binding(Vec<Key>);
+
+impl Binding {
+    pub fn get_keys(&self) -> &[Key] {
+        &self.0
+    }
+
+    // --- Note ---
+    // `<` without a known name after it is taken as it is, so old single character mappings
+    // still work
+    fn parse_keys(value: &str) -> Result<Vec<Key>, String> {
+        let mut keys = Vec::new();
+        let mut rest = value;
+
+        while let Some(ch) = rest.chars().next() {
+            let special = rest
+                .strip_prefix('<')
+                .and_then(|inner| inner.split_once('>'))
+                .and_then(|(name, after)| parse_name(name).map(|key| (key, after)));
+
+            match special {
+                Some((key, after)) => {
+                    keys.push(key);
+                    rest = after;
+                }
+                None if ch == '<' && rest.len() > 1 && rest.contains('>') => {
+                    let name = &rest[1..rest.find('>').unwrap_or(rest.len())];
+
+                    return Err(format!("No such key '<{name}>' availabe"));
+                }
+                None => {
+                    keys.push(Key::Char(ch));
+                    rest = &rest[ch.len_utf8()..];
+                }
+            }
+        }
+
+        Ok(keys)
+    }
+}
+
+impl FromStr for Binding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let keys = Binding::parse_keys(s.trim())?;
+
+        if keys.is_empty() {
+            return Err(String::from("Key mapping can not be empty"));
+        }
+
+        Ok(Binding(keys))
+    }
+}
+
+impl From<char> for Binding {
+    fn from(ch: char) -> Self {
+        Binding(vec![Key::Char(ch)])
+    }
+}
+
+impl From<Key> for Binding {
+    fn from(key: Key) -> Self {
+        Binding(vec![key])
+    }
+}
+
+impl fmt::Display for Binding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let keys: String = self.0.iter().map(format_key).collect();
+
+        write!(f, "{keys}")
+    }
+}
+
+pub fn parse_bindings(value: &str) -> Result<Vec<Binding>, String> {
+    let bindings = value
+        .split_whitespace()
+        .map(Binding::from_str)
+        .collect::<Result<Vec<Binding>, String>>()?;
+
+    if bindings.is_empty() {
+        return Err(String::from("Key mapping can not be empty"));
+    }
+
+    Ok(bindings)
+}
+
+pub fn format_bindings(bindings: &[Binding]) -> String {
+    bindings
+        .iter()
+        .map(|binding| binding.to_string())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bindings() {
+        let bindings = parse_bindings("gg <C-d> <Enter> <up> <F5> x<lt> <").unwrap();
+        let keys: Vec<&[Key]> = bindings.iter().map(|binding| binding.get_keys()).collect();
+
+        assert_eq!(
+            keys,
+            vec![
+                &[Key::Char('g'), Key::Char('g')][..],
+                &[Key::Ctrl('d')],
+                &[Key::Char('\n')],
+                &[Key::Up],
+                &[Key::F(5)],
+                &[Key::Char('x'), Key::Char('<')],
+                &[Key::Char('<')],
+            ]
+        );
+        assert_eq!(
+            format_bindings(&bindings),
+            "gg <C-d> <Enter> <Up> <F5> x<lt> <lt>"
+        );
+
+        assert!(parse_bindings("<Foo>").is_err());
+        assert!(parse_bindings("<F13>").is_err());
+        assert!(parse_bindings("  ").is_err());
+    }
+}
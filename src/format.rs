@@ -0,0 +1,70 @@
+pub mod csv;
+pub mod json;
+pub mod todotxt;
+
+use crate::app::tabs::todo::TodoItem;
+
+use std::fmt;
+
+use core::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Format {
+    TodoTxt,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Format::TodoTxt => "todotxt",
+            Format::Json => "json",
+            Format::Csv => "csv",
+        }
+    }
+
+    pub fn export(&self, todo_list: &[TodoItem]) -> String {
+        match self {
+            Format::TodoTxt => todotxt::format_list(todo_list),
+            Format::Json => json::format_list(todo_list),
+            Format::Csv => csv::format_list(todo_list),
+        }
+    }
+
+    pub fn import(&self, text: &str) -> Result<Vec<TodoItem>, ImportError> {
+        match self {
+            Format::TodoTxt => todotxt::parse_list(text),
+            Format::Json => json::parse_list(text),
+            Format::Csv => csv::parse_list(text),
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "todotxt" => Ok(Format::TodoTxt),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err("No such format availabe, try using 'todotxt/json/csv'"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ImportError {
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {line}: {}", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
@@ -0,0 +1,263 @@
+// --- Note ---
+// Format description: https://www.rfc-editor.org/rfc/rfc4180
+// First row is the header, columns are looked up by its names, so they can be in any order and
+// unknown ones are ignored. Empty cells of optional columns are read as no value
+
+use super::ImportError;
+use crate::app::reminder::{DecisionRecord, DECISION_SEPARATOR};
+use crate::app::tabs::todo::{Priority, Status, TodoItem};
+
+use core::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+const HEADER: [&str; 10] = [
+    "id",
+    "status",
+    "priority",
+    "description",
+    "date_created",
+    "date_modified",
+    "date_completed",
+    "due",
+    "scheduled",
+    "decisions",
+];
+
+fn format_cell(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+pub fn format_item(item: &TodoItem) -> String {
+    let cells = [
+        item.id().to_string(),
+        item.status.as_str().to_lowercase(),
+        item.priority
+            .map_or(String::new(), |priority| priority.as_str().to_owned()),
+        item.description.clone(),
+        item.date_created.to_rfc3339(),
+        item.date_modified.to_rfc3339(),
+        item.date_completed
+            .map_or(String::new(), |date| date.to_rfc3339()),
+        item.due.map_or(String::new(), |date| date.to_string()),
+        item.scheduled
+            .map_or(String::new(), |date| date.to_string()),
+        item.decisions
+            .iter()
+            .map(|record| record.to_string())
+            .collect::<Vec<String>>()
+            .join(DECISION_SEPARATOR),
+    ];
+
+    cells
+        .iter()
+        .map(|cell| format_cell(cell))
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+pub fn format_list(todo_list: &[TodoItem]) -> String {
+    let mut text = HEADER.join(",") + "\r\n";
+
+    for item in todo_list {
+        text += &(format_item(item) + "\r\n");
+    }
+
+    text
+}
+
+fn parse_rows(text: &str) -> Result<Vec<(usize, Vec<String>)>, ImportError> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut cell = String::new();
+    let mut line = 1;
+    let mut row_line = 1;
+    let mut is_quoted = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match (is_quoted, char) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                chars.next();
+                cell.push('"');
+            }
+            (true, '"') => is_quoted = false,
+            (true, char) => {
+                if char == '\n' {
+                    line += 1;
+                }
+
+                cell.push(char);
+            }
+            (false, '"') if cell.is_empty() => is_quoted = true,
+            (false, ',') => row.push(std::mem::take(&mut cell)),
+            (false, '\r') if chars.peek() == Some(&'\n') => {}
+            (false, '\n') => {
+                row.push(std::mem::take(&mut cell));
+                rows.push((row_line, std::mem::take(&mut row)));
+                line += 1;
+                row_line = line;
+            }
+            (false, char) => cell.push(char),
+        }
+    }
+
+    if is_quoted {
+        return Err(ImportError {
+            line: Some(row_line),
+            message: String::from("quoted cell is never closed"),
+        });
+    }
+
+    if !cell.is_empty() || !row.is_empty() {
+        row.push(cell);
+        rows.push((row_line, row));
+    }
+
+    Ok(rows
+        .into_iter()
+        .filter(|(_, row)| row.iter().any(|cell| !cell.is_empty()))
+        .collect())
+}
+
+pub fn parse_list(text: &str) -> Result<Vec<TodoItem>, ImportError> {
+    let mut rows = parse_rows(text)?.into_iter();
+
+    let Some((_, header)) = rows.next() else {
+        return Ok(Vec::new());
+    };
+
+    for name in ["id", "status", "description"] {
+        if !header.iter().any(|column| column == name) {
+            return Err(ImportError {
+                line: Some(1),
+                message: format!("header has no '{name}' column"),
+            });
+        }
+    }
+
+    rows.map(|(line, row)| {
+        let get = |name: &str| {
+            header
+                .iter()
+                .position(|column| column == name)
+                .and_then(|index| row.get(index))
+                .map(String::as_str)
+                .filter(|value| !value.is_empty())
+        };
+        let invalid = |name: &str, value: &str| ImportError {
+            line: Some(line),
+            message: format!("'{value}' is not a valid '{name}'"),
+        };
+        let require = |name: &str| {
+            get(name).ok_or_else(|| ImportError {
+                line: Some(line),
+                message: format!("'{name}' is missing"),
+            })
+        };
+
+        let id = require("id")?;
+        let status = require("status")?;
+        let mut item = TodoItem::new(
+            id.parse().map_err(|_| invalid("id", id))?,
+            require("description")?,
+        );
+
+        item.status = Status::from_str(status).map_err(|_| invalid("status", status))?;
+
+        for (name, date) in [
+            ("date_created", &mut item.date_created),
+            ("date_modified", &mut item.date_modified),
+        ] {
+            if let Some(value) = get(name) {
+                *date = DateTime::parse_from_rfc3339(value)
+                    .map_err(|_| invalid(name, value))?
+                    .with_timezone(&Utc);
+            }
+        }
+
+        if let Some(value) = get("date_completed") {
+            item.date_completed = Some(
+                DateTime::parse_from_rfc3339(value)
+                    .map_err(|_| invalid("date_completed", value))?
+                    .with_timezone(&Utc),
+            );
+        }
+
+        for (name, date) in [("due", &mut item.due), ("scheduled", &mut item.scheduled)] {
+            if let Some(value) = get(name) {
+                *date = Some(NaiveDate::from_str(value).map_err(|_| invalid(name, value))?);
+            }
+        }
+
+        if let Some(value) = get("priority") {
+            item.priority =
+                Some(Priority::from_str(value).map_err(|_| invalid("priority", value))?);
+        }
+
+        if let Some(value) = get("decisions") {
+            item.decisions = value
+                .split(DECISION_SEPARATOR)
+                .map(|record| {
+                    DecisionRecord::from_str(record).map_err(|_| invalid("decisions", value))
+                })
+                .collect::<Result<Vec<DecisionRecord>, ImportError>>()?;
+        }
+
+        Ok(item)
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut first = TodoItem::new(3, "Pay \"rent\", bills\nand taxes");
+
+        first.set_status(Status::Done);
+        first.scheduled = NaiveDate::from_ymd_opt(2026, 11, 3);
+        first.priority = Some(Priority::C);
+        first.decisions = vec![
+            DecisionRecord::from_str("2026-10-17 carry").unwrap(),
+            DecisionRecord::from_str("2026-10-18 reschedule 2026-11-03").unwrap(),
+        ];
+
+        let todo_list = vec![first, TodoItem::new(5, "Walk")];
+
+        assert_eq!(parse_list(&format_list(&todo_list)).unwrap(), todo_list);
+    }
+
+    #[test]
+    fn test_parse_list() {
+        let todo_list =
+            parse_list("description,id,status\nWalk,1,todo\n\n\"Pay\nrent\",2,done\n").unwrap();
+
+        assert_eq!(todo_list.len(), 2);
+        assert_eq!(todo_list[1].id(), 2);
+        assert_eq!(todo_list[1].description, "Pay\nrent");
+        assert_eq!(todo_list[1].status, Status::Done);
+
+        assert_eq!(
+            parse_list("id,status,description\n1,todo,Walk\n\"2,later,Pay rent"),
+            Err(ImportError {
+                line: Some(3),
+                message: String::from("quoted cell is never closed")
+            })
+        );
+        assert_eq!(
+            parse_list("id,status,description\n1,todo,Walk\n2,later,Pay rent"),
+            Err(ImportError {
+                line: Some(3),
+                message: String::from("'later' is not a valid 'status'")
+            })
+        );
+        assert!(parse_list("id,description\n1,Walk").is_err());
+    }
+}
@@ -0,0 +1,192 @@
+use super::ImportError;
+use crate::app::reminder::DecisionRecord;
+use crate::app::tabs::todo::{Priority, Status, TodoItem};
+
+use core::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_json::{json, Map, Value};
+
+// --- Note ---
+// Version is bumped whenever a field changes its meaning or gets removed, new optional fields
+// don't need a new version. Files with a newer version are refused instead of being read wrong
+pub const SCHEMA_VERSION: u64 = 1;
+
+pub fn format_item(item: &TodoItem) -> Value {
+    json!({
+        "id": item.id(),
+        "status": item.status.as_str().to_lowercase(),
+        "description": item.description,
+        "date_created": item.date_created.to_rfc3339(),
+        "date_modified": item.date_modified.to_rfc3339(),
+        "due": item.due.map(|date| date.to_string()),
+        "scheduled": item.scheduled.map(|date| date.to_string()),
+        "priority": item.priority.map(|priority| priority.as_str()),
+        "date_completed": item.date_completed.map(|date| date.to_rfc3339()),
+        "decisions": item.decisions.iter().map(|record| record.to_string()).collect::<Vec<String>>(),
+    })
+}
+
+pub fn format_list(todo_list: &[TodoItem]) -> String {
+    let value = json!({
+        "version": SCHEMA_VERSION,
+        "todos": todo_list.iter().map(format_item).collect::<Vec<Value>>(),
+    });
+
+    serde_json::to_string_pretty(&value).expect("Couldn't serialize todo list") + "\n"
+}
+
+fn parse_item(value: &Value, position: usize) -> Result<TodoItem, ImportError> {
+    let error = |message: String| ImportError {
+        line: Some(position),
+        message,
+    };
+    let object = value
+        .as_object()
+        .ok_or_else(|| error(String::from("todo is not an object")))?;
+
+    let get_str = |key: &str| -> Result<Option<&str>, ImportError> {
+        match object.get(key) {
+            None | Some(Value::Null) => Ok(None),
+            Some(Value::String(value)) => Ok(Some(value)),
+            Some(_) => Err(error(format!("'{key}' is not a string"))),
+        }
+    };
+    let require_str = |key: &str| -> Result<&str, ImportError> {
+        get_str(key)?.ok_or_else(|| error(format!("'{key}' is missing")))
+    };
+    let invalid = |key: &str, value: &str| error(format!("'{value}' is not a valid '{key}'"));
+
+    let id = object
+        .get("id")
+        .and_then(Value::as_u64)
+        .and_then(|id| u16::try_from(id).ok())
+        .ok_or_else(|| error(String::from("'id' is missing or not a valid id")))?;
+    let status = require_str("status")?;
+    let description = require_str("description")?;
+
+    let mut item = TodoItem::new(id, description);
+
+    item.status = Status::from_str(status).map_err(|_| invalid("status", status))?;
+
+    for (key, date) in [
+        ("date_created", &mut item.date_created),
+        ("date_modified", &mut item.date_modified),
+    ] {
+        let value = require_str(key)?;
+
+        *date = DateTime::parse_from_rfc3339(value)
+            .map_err(|_| invalid(key, value))?
+            .with_timezone(&Utc);
+    }
+
+    for (key, date) in [("due", &mut item.due), ("scheduled", &mut item.scheduled)] {
+        if let Some(value) = get_str(key)? {
+            *date = Some(NaiveDate::from_str(value).map_err(|_| invalid(key, value))?);
+        }
+    }
+
+    if let Some(value) = get_str("priority")? {
+        item.priority = Some(Priority::from_str(value).map_err(|_| invalid("priority", value))?);
+    }
+
+    if let Some(value) = get_str("date_completed")? {
+        item.date_completed = Some(
+            DateTime::parse_from_rfc3339(value)
+                .map_err(|_| invalid("date_completed", value))?
+                .with_timezone(&Utc),
+        );
+    }
+
+    match object.get("decisions") {
+        None | Some(Value::Null) => {}
+        Some(Value::Array(values)) => {
+            for value in values {
+                let record = value
+                    .as_str()
+                    .and_then(|record| DecisionRecord::from_str(record).ok())
+                    .ok_or_else(|| error(format!("'{value}' is not a valid 'decisions'")))?;
+
+                item.decisions.push(record);
+            }
+        }
+        Some(_) => return Err(error(String::from("'decisions' is not a list"))),
+    }
+
+    Ok(item)
+}
+
+pub fn parse_list(text: &str) -> Result<Vec<TodoItem>, ImportError> {
+    let error = |message: String| ImportError {
+        line: None,
+        message,
+    };
+
+    let value: Value = serde_json::from_str(text).map_err(|err| ImportError {
+        line: Some(err.line()),
+        message: format!("not a valid JSON: {err}"),
+    })?;
+    let object: &Map<String, Value> = value.as_object().ok_or_else(|| {
+        error(String::from(
+            "expected an object with 'version' and 'todos'",
+        ))
+    })?;
+
+    match object.get("version").and_then(Value::as_u64) {
+        Some(version) if version <= SCHEMA_VERSION => {}
+        Some(version) => {
+            return Err(error(format!(
+                "version {version} is newer than the supported version {SCHEMA_VERSION}"
+            )))
+        }
+        None => return Err(error(String::from("'version' is missing"))),
+    }
+
+    object
+        .get("todos")
+        .and_then(Value::as_array)
+        .ok_or_else(|| error(String::from("'todos' is missing or not a list")))?
+        .iter()
+        .enumerate()
+        .map(|(index, value)| parse_item(value, index + 1))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut first = TodoItem::new(3, "Pay \"rent\"\nnow");
+
+        first.set_status(Status::Done);
+        first.due = NaiveDate::from_ymd_opt(2026, 11, 3);
+        first.priority = Some(Priority::B);
+        first.decisions = vec![DecisionRecord::from_str("2026-10-18 drop").unwrap()];
+
+        let todo_list = vec![first, TodoItem::new(5, "Walk")];
+        let parsed = parse_list(&format_list(&todo_list)).unwrap();
+
+        assert_eq!(parsed, todo_list);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_list(r#"{"version": 2, "todos": []}"#)
+                .unwrap_err()
+                .message,
+            "version 2 is newer than the supported version 1"
+        );
+        assert_eq!(
+            parse_list(r#"{"version": 1, "todos": [{"id": 1, "status": "later"}]}"#).unwrap_err(),
+            ImportError {
+                line: Some(1),
+                message: String::from("'description' is missing")
+            }
+        );
+        assert_eq!(parse_list("[]").unwrap_err().line, None);
+        assert_eq!(parse_list("{\n\"version\": }").unwrap_err().line, Some(2));
+    }
+}
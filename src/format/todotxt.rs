@@ -0,0 +1,238 @@
+// --- Note ---
+// Format description: https://github.com/todotxt/todo.txt
+// Dates which todo.txt has no place for are kept as `key:value` extensions used by other tools,
+// `due:` for due date, `t:` for scheduled (threshold) date and `pri:` for the priority of
+// completed items, which loses its `(A)` prefix
+// Priorities past E have no equivalent here and are rejected rather than guessed
+
+use super::ImportError;
+use crate::app::tabs::todo::{Priority, Status, TodoItem, DATE_FORMAT};
+
+use core::str::FromStr;
+
+use chrono::{DateTime, Local, NaiveDate, TimeZone, Utc};
+
+const DONE_MARK: &str = "x";
+const DUE_KEY: &str = "due";
+const SCHEDULED_KEY: &str = "t";
+const PRIORITY_KEY: &str = "pri";
+
+fn parse_date(word: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(word, DATE_FORMAT).ok()
+}
+
+fn to_date_time(date: NaiveDate) -> DateTime<Utc> {
+    let midnight = date.and_hms_opt(0, 0, 0).unwrap();
+
+    Local
+        .from_local_datetime(&midnight)
+        .earliest()
+        .map(|date_time| date_time.with_timezone(&Utc))
+        .unwrap_or_else(|| midnight.and_utc())
+}
+
+fn to_local_date(date_time: &DateTime<Utc>) -> NaiveDate {
+    date_time.with_timezone(&Local).date_naive()
+}
+
+fn format_date(date: NaiveDate) -> String {
+    date.format(DATE_FORMAT).to_string()
+}
+
+fn get_priority_letter(word: &str) -> Option<&str> {
+    let letter = word.strip_prefix('(')?.strip_suffix(')')?;
+    let mut chars = letter.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some('A'..='Z'), None) => Some(letter),
+        _ => None,
+    }
+}
+
+fn parse_priority(word: &str, letter: &str, line: usize) -> Result<Priority, ImportError> {
+    Priority::from_str(letter).map_err(|err| ImportError {
+        line: Some(line),
+        message: format!("'{word}' is not a valid value: {err}"),
+    })
+}
+
+pub fn format_item(item: &TodoItem) -> String {
+    let mut words = Vec::new();
+
+    if let Some(date_completed) = item.get_date_completed() {
+        words.push(DONE_MARK.to_owned());
+        words.push(format_date(to_local_date(&date_completed)));
+    } else if let Some(priority) = item.priority {
+        words.push(format!("({})", priority.as_str()));
+    }
+
+    words.push(format_date(to_local_date(&item.date_created)));
+    words.push(item.description.replace(['\n', '\r'], " "));
+
+    if let Some(due) = item.due {
+        words.push(format!("{DUE_KEY}:{}", format_date(due)));
+    }
+
+    if let Some(scheduled) = item.scheduled {
+        words.push(format!("{SCHEDULED_KEY}:{}", format_date(scheduled)));
+    }
+
+    if let (Status::Done, Some(priority)) = (&item.status, item.priority) {
+        words.push(format!("{PRIORITY_KEY}:{}", priority.as_str()));
+    }
+
+    words.join(" ")
+}
+
+pub fn format_list(todo_list: &[TodoItem]) -> String {
+    todo_list
+        .iter()
+        .map(|item| format_item(item) + "\n")
+        .collect()
+}
+
+pub fn parse_item(text: &str, line: usize) -> Result<TodoItem, ImportError> {
+    let mut words = text.split_whitespace().peekable();
+    let mut item = TodoItem::new(0, "");
+
+    if words.peek() == Some(&DONE_MARK) {
+        words.next();
+        item.status = Status::Done;
+
+        if let Some(date) = words.peek().and_then(|word| parse_date(word)) {
+            words.next();
+            item.date_completed = Some(to_date_time(date));
+        }
+    } else if let Some(word) = words.next_if(|word| get_priority_letter(word).is_some()) {
+        let letter = get_priority_letter(word).unwrap();
+
+        item.priority = Some(parse_priority(word, letter, line)?);
+    }
+
+    if let Some(date) = words.peek().and_then(|word| parse_date(word)) {
+        words.next();
+        item.date_created = to_date_time(date);
+    }
+
+    let mut description = Vec::new();
+
+    for word in words {
+        let invalid = || ImportError {
+            line: Some(line),
+            message: format!("'{word}' is not a valid value"),
+        };
+
+        match word.split_once(':') {
+            Some((DUE_KEY, value)) => item.due = Some(parse_date(value).ok_or_else(invalid)?),
+            Some((SCHEDULED_KEY, value)) => {
+                item.scheduled = Some(parse_date(value).ok_or_else(invalid)?)
+            }
+            Some((PRIORITY_KEY, value)) => item.priority = Some(parse_priority(word, value, line)?),
+            _ => description.push(word),
+        }
+    }
+
+    if description.is_empty() {
+        return Err(ImportError {
+            line: Some(line),
+            message: String::from("todo has no description"),
+        });
+    }
+
+    item.description = description.join(" ");
+
+    Ok(item)
+}
+
+pub fn parse_list(text: &str) -> Result<Vec<TodoItem>, ImportError> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| parse_item(line, index + 1))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> NaiveDate {
+        parse_date(value).unwrap()
+    }
+
+    #[test]
+    fn test_parse_item() {
+        let item = parse_item("(A) 2026-10-01 Call mom +family @phone due:2026-10-20", 1).unwrap();
+
+        assert_eq!(item.status, Status::Todo);
+        assert_eq!(item.priority, Some(Priority::A));
+        assert_eq!(to_local_date(&item.date_created), date("2026-10-01"));
+        assert_eq!(item.description, "Call mom +family @phone");
+        assert_eq!(item.due, Some(date("2026-10-20")));
+
+        let item = parse_item("x 2026-10-18 2026-10-01 Pay rent t:2026-10-15 pri:E", 1).unwrap();
+
+        assert_eq!(item.status, Status::Done);
+        assert_eq!(
+            item.date_completed.as_ref().map(to_local_date),
+            Some(date("2026-10-18"))
+        );
+        assert_eq!(to_local_date(&item.date_created), date("2026-10-01"));
+        assert_eq!(item.scheduled, Some(date("2026-10-15")));
+        assert_eq!(item.priority, Some(Priority::E));
+
+        let item = parse_item("xylophone lessons (A)", 1).unwrap();
+
+        assert_eq!(item.status, Status::Todo);
+        assert_eq!(item.priority, None);
+        assert_eq!(item.description, "xylophone lessons (A)");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_list("Walk\n\nPay rent due:someday"),
+            Err(ImportError {
+                line: Some(3),
+                message: String::from("'due:someday' is not a valid value")
+            })
+        );
+        assert!(parse_item("x 2026-10-18", 1).is_err());
+        assert_eq!(
+            parse_item("(F) Walk", 2),
+            Err(ImportError {
+                line: Some(2),
+                message: String::from(
+                    "'(F)' is not a valid value: No such priority availabe, try using 'A/B/C/D/E'"
+                )
+            })
+        );
+        assert!(parse_item("x Pay rent pri:Z", 1).is_err());
+    }
+
+    #[test]
+    fn test_format_local_dates() {
+        let mut item = TodoItem::new(0, "Walk");
+        let late = Local
+            .with_ymd_and_hms(2026, 10, 18, 23, 30, 0)
+            .unwrap()
+            .with_timezone(&Utc);
+
+        item.date_created = late;
+        item.status = Status::Done;
+        item.date_completed = Some(late);
+
+        assert_eq!(format_item(&item), "x 2026-10-18 2026-10-18 Walk");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for line in [
+            "(B) 2026-10-01 Call mom +family @phone due:2026-10-20 t:2026-10-19",
+            "x 2026-10-18 2026-10-01 Pay rent pri:A",
+            "2026-10-01 Walk",
+        ] {
+            assert_eq!(format_item(&parse_item(line, 1).unwrap()), line);
+        }
+    }
+}
@@ -1,2 +1,9 @@
-pub mod config;
 pub mod app;
+pub mod cli;
+pub mod config;
+pub mod format;
+pub mod paths;
+pub mod storage;
+
+#[cfg(test)]
+mod test_helpers;
@@ -1,46 +1,58 @@
 use std::env;
-use std::path::PathBuf;
+use std::io::stdout;
+use std::process;
 
 use mindr::app::App;
+use mindr::cli::{Command, Error, Options};
 use mindr::config::Config;
+use mindr::paths;
+use mindr::storage::Backend;
 
-struct Path {
-    base_path: [String; 4],
+fn exit_with(err: Error) -> ! {
+    eprintln!("{err}");
+    process::exit(err.exit_code());
 }
 
-impl Path {
-    fn new() -> Self {
-        let user_name = env::var("USERNAME").expect("Couldn't get system user");
-        let base_path: [String; 4] = [
-            String::from("/home"),
-            user_name,
-            String::from(".config"),
-            String::from("mindr"),
-        ];
-
-        Path { base_path }
-    }
-
-    fn get(&self) -> [PathBuf; 2] {
-        let mut config_path = self.base_path.iter().collect::<PathBuf>();
-        let mut app_path = self.base_path.iter().collect::<PathBuf>();
-
-        config_path.push("mindr.conf");
-        app_path.push("todo.txt");
-
-        [config_path, app_path]
-    }
+fn resolve<T>(result: Result<T, String>) -> T {
+    result.unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    })
 }
 
 // TODO: think if it's good to add other crate (not mindr) kind of like namespace for config
 fn main() {
-    let path = Path::new();
-    let [config_path, app_path] = path.get();
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (options, args) = Options::parse(&args).unwrap_or_else(|err| exit_with(err));
+
+    let env = |key: &str| env::var(key).ok();
+
+    let data_path = resolve(paths::get_data_path(options.data, &env));
+    let config_path = paths::get_config_path(options.config, &env);
+
+    match Command::parse(args) {
+        Ok(Some(command)) => {
+            let backend = match &config_path {
+                Ok(path) if path.exists() => Config::init(path).storage_backend,
+                _ => Backend::Text,
+            };
+
+            match command.execute(&backend, &data_path, &mut stdout()) {
+                Ok(()) | Err(Error::OutputClosed) => {}
+                Err(err) => exit_with(err),
+            }
+
+            return;
+        }
+        Ok(None) => {}
+        Err(err) => exit_with(err),
+    }
 
+    let config_path = resolve(config_path);
     let config = Config::init(&config_path);
-    let mut app = App::init(&config, &app_path);
+    let mut app = resolve(App::init(config, &data_path));
 
-    app.run();
+    resolve(app.run());
 }
 
 // -- For Future: --
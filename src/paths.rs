@@ -0,0 +1,160 @@
+use std::path::PathBuf;
+
+const APP_DIR: &str = "mindr";
+const CONFIG_FILE: &str = "mindr.conf";
+const DATA_FILE: &str = "todo.txt";
+
+// --- Note ---
+// According to XDG Base Directory spec empty and relative values should be treated as unset
+fn get_dir(env: &impl Fn(&str) -> Option<String>, key: &str) -> Option<PathBuf> {
+    env(key)
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+}
+
+fn get_file(env: &impl Fn(&str) -> Option<String>, key: &str) -> Option<PathBuf> {
+    env(key)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+fn get_base_dir(
+    env: &impl Fn(&str) -> Option<String>,
+    xdg_key: &str,
+    home_fallback: &[&str],
+) -> Result<PathBuf, String> {
+    if let Some(dir) = get_dir(env, xdg_key) {
+        return Ok(dir);
+    }
+
+    let home = get_dir(env, "HOME").ok_or_else(|| {
+        format!("Couldn't find the home directory, set either 'HOME' or '{xdg_key}'")
+    })?;
+
+    Ok(home_fallback
+        .iter()
+        .fold(home, |path, part| path.join(part)))
+}
+
+pub fn get_config_path(
+    flag: Option<PathBuf>,
+    env: &impl Fn(&str) -> Option<String>,
+) -> Result<PathBuf, String> {
+    match flag.or_else(|| get_file(env, "MINDR_CONFIG")) {
+        Some(path) => Ok(path),
+        None => Ok(get_base_dir(env, "XDG_CONFIG_HOME", &[".config"])?
+            .join(APP_DIR)
+            .join(CONFIG_FILE)),
+    }
+}
+
+pub fn get_data_path(
+    flag: Option<PathBuf>,
+    env: &impl Fn(&str) -> Option<String>,
+) -> Result<PathBuf, String> {
+    if let Some(path) = flag.or_else(|| get_file(env, "MINDR_DATA")) {
+        return Ok(path);
+    }
+
+    let data = get_base_dir(env, "XDG_DATA_HOME", &[".local", "share"])?
+        .join(APP_DIR)
+        .join(DATA_FILE);
+
+    Ok(get_legacy_data_path(env).unwrap_or(data))
+}
+
+// --- Note ---
+// Earlier versions kept `todo.txt` next to `mindr.conf`, so the file is picked up from there as
+// long as it exists to not lose the user's data
+fn get_legacy_data_path(env: &impl Fn(&str) -> Option<String>) -> Option<PathBuf> {
+    let legacy = get_base_dir(env, "XDG_CONFIG_HOME", &[".config"])
+        .ok()?
+        .join(APP_DIR)
+        .join(DATA_FILE);
+
+    legacy.exists().then_some(legacy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashMap;
+
+    fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        let vars: HashMap<&str, &str> = vars.iter().cloned().collect();
+
+        move |key| vars.get(key).map(|value| value.to_string())
+    }
+
+    #[test]
+    fn test_home_fallback() {
+        let env = env(&[("HOME", "/nonexistent/home")]);
+
+        assert_eq!(
+            get_config_path(None, &env),
+            Ok(PathBuf::from("/nonexistent/home/.config/mindr/mindr.conf"))
+        );
+        assert_eq!(
+            get_data_path(None, &env),
+            Ok(PathBuf::from(
+                "/nonexistent/home/.local/share/mindr/todo.txt"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_xdg_dirs() {
+        let env = env(&[
+            ("HOME", "/nonexistent/home"),
+            ("XDG_CONFIG_HOME", "/nonexistent/config"),
+            ("XDG_DATA_HOME", "relative/data"),
+        ]);
+
+        assert_eq!(
+            get_config_path(None, &env),
+            Ok(PathBuf::from("/nonexistent/config/mindr/mindr.conf"))
+        );
+        assert_eq!(
+            get_data_path(None, &env),
+            Ok(PathBuf::from(
+                "/nonexistent/home/.local/share/mindr/todo.txt"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_overrides() {
+        let env = env(&[
+            ("MINDR_CONFIG", "/env/mindr.conf"),
+            ("MINDR_DATA", "/env/todo.txt"),
+        ]);
+
+        assert_eq!(
+            get_config_path(None, &env),
+            Ok(PathBuf::from("/env/mindr.conf"))
+        );
+        assert_eq!(
+            get_data_path(None, &env),
+            Ok(PathBuf::from("/env/todo.txt"))
+        );
+
+        let config_flag = Some(PathBuf::from("/flag/mindr.conf"));
+        let data_flag = Some(PathBuf::from("/flag/todo.txt"));
+
+        assert_eq!(
+            get_config_path(config_flag, &env),
+            Ok(PathBuf::from("/flag/mindr.conf"))
+        );
+        assert_eq!(
+            get_data_path(data_flag, &env),
+            Ok(PathBuf::from("/flag/todo.txt"))
+        );
+    }
+
+    #[test]
+    fn test_missing_home() {
+        assert!(get_config_path(None, &env(&[])).is_err());
+        assert!(get_data_path(None, &env(&[])).is_err());
+    }
+}
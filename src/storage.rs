@@ -0,0 +1,170 @@
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+
+mod backups;
+mod files;
+mod memory;
+mod text;
+
+pub use backups::{list_backups, restore};
+pub use files::{read_reminded_date, write_reminded_date};
+pub use memory::MemoryStore;
+pub use text::{get_rejected_path, ParseError, TextStore};
+
+use crate::app::tabs::todo::TodoItem;
+
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use core::str::FromStr;
+
+#[cfg(feature = "sqlite")]
+const SQLITE_EXTENSION: &str = "db";
+
+pub trait TodoStore: fmt::Debug {
+    fn load(&self) -> io::Result<(Vec<TodoItem>, Vec<ParseError>)>;
+    fn save(&self, todo_list: &[TodoItem]) -> io::Result<()>;
+    fn insert(&self, item: &TodoItem) -> io::Result<()>;
+    fn update(&self, item: &TodoItem) -> io::Result<()>;
+    fn delete(&self, id: u16) -> io::Result<()>;
+}
+
+impl<T: TodoStore + ?Sized> TodoStore for Box<T> {
+    fn load(&self) -> io::Result<(Vec<TodoItem>, Vec<ParseError>)> {
+        (**self).load()
+    }
+
+    fn save(&self, todo_list: &[TodoItem]) -> io::Result<()> {
+        (**self).save(todo_list)
+    }
+
+    fn insert(&self, item: &TodoItem) -> io::Result<()> {
+        (**self).insert(item)
+    }
+
+    fn update(&self, item: &TodoItem) -> io::Result<()> {
+        (**self).update(item)
+    }
+
+    fn delete(&self, id: u16) -> io::Result<()> {
+        (**self).delete(id)
+    }
+}
+
+fn not_found(id: u16) -> io::Error {
+    io::Error::new(io::ErrorKind::NotFound, format!("No todo with id '{id}'"))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Backend {
+    Text,
+    Sqlite,
+}
+
+impl Backend {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Backend::Text => "text",
+            Backend::Sqlite => "sqlite",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            Backend::Text => Backend::Sqlite,
+            Backend::Sqlite => Backend::Text,
+        }
+    }
+
+    pub fn is_available(&self) -> bool {
+        match self {
+            Backend::Text => true,
+            Backend::Sqlite => cfg!(feature = "sqlite"),
+        }
+    }
+
+    pub fn get_path(&self, path: &Path) -> PathBuf {
+        match self {
+            Backend::Text => path.to_owned(),
+            #[cfg(feature = "sqlite")]
+            Backend::Sqlite => path.with_extension(SQLITE_EXTENSION),
+            #[cfg(not(feature = "sqlite"))]
+            Backend::Sqlite => path.to_owned(),
+        }
+    }
+}
+
+impl FromStr for Backend {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Backend::Text),
+            "sqlite" => Ok(Backend::Sqlite),
+            _ => Err("No such storage backend availabe, try using 'text/sqlite'"),
+        }
+    }
+}
+
+#[cfg(feature = "sqlite")]
+fn open_sqlite(path: &Path) -> io::Result<sqlite::SqliteStore> {
+    let db_path = Backend::Sqlite.get_path(path);
+    let is_new = !db_path.exists();
+    let store = sqlite::SqliteStore::open(&db_path)?;
+
+    if is_new && path.exists() && path != db_path && store.is_empty()? {
+        let (todo_list, _) = text::load(path)?;
+
+        store.save(&todo_list)?;
+    }
+
+    Ok(store)
+}
+
+pub fn open(backend: &Backend, path: &Path) -> Result<Box<dyn TodoStore>, String> {
+    match backend {
+        Backend::Text => Ok(Box::new(TextStore::new(path))),
+        #[cfg(feature = "sqlite")]
+        Backend::Sqlite => open_sqlite(path)
+            .map(|store| Box::new(store) as Box<dyn TodoStore>)
+            .map_err(|err| format!("Couldn't open SQLite database: {err}")),
+        #[cfg(not(feature = "sqlite"))]
+        Backend::Sqlite => Err(String::from(
+            "mindr was built without SQLite support, rebuild it with '--features sqlite' or set '[storage] backend = text'",
+        )),
+    }
+}
+
+#[cfg(all(test, feature = "sqlite"))]
+mod tests {
+    use super::text::{load, save};
+    use super::*;
+    use crate::test_helpers::get_temp_path;
+
+    use std::fs;
+
+    #[test]
+    fn test_sqlite_migrates_text_file() {
+        let path = get_temp_path("storage_migrate");
+        let db_path = Backend::Sqlite.get_path(&path);
+        let todo_list = vec![TodoItem::new(0, "Walk"), TodoItem::new(3, "Pay rent")];
+
+        if db_path.exists() {
+            fs::remove_file(&db_path).unwrap();
+        }
+
+        save(&path, &todo_list).unwrap();
+
+        let store = open(&Backend::Sqlite, &path).unwrap();
+
+        assert_eq!(store.load().unwrap().0, todo_list);
+
+        store.delete(0).unwrap();
+
+        let store = open(&Backend::Sqlite, &path).unwrap();
+
+        assert_eq!(store.load().unwrap().0, todo_list[1..]);
+        assert_eq!(load(&path).unwrap().0, todo_list);
+    }
+}
@@ -0,0 +1,108 @@
+use super::files::{with_extension, write_atomic};
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+
+const BACKUP_EXTENSION: &str = "backups";
+const BACKUP_COUNT: usize = 10;
+const BACKUP_DATE_FORMAT: &str = "%Y%m%dT%H%M%S%.3f";
+
+fn get_backup_dir(path: &Path) -> PathBuf {
+    with_extension(path, BACKUP_EXTENSION)
+}
+
+pub fn list_backups(path: &Path) -> io::Result<Vec<PathBuf>> {
+    let backup_dir = get_backup_dir(path);
+
+    if !backup_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let prefix = format!(
+        "{}.",
+        path.file_name().unwrap_or_default().to_string_lossy()
+    );
+    let mut backups: Vec<PathBuf> = fs::read_dir(backup_dir)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|backup| {
+            backup
+                .file_name()
+                .map(|name| name.to_string_lossy().starts_with(&prefix))
+                .unwrap_or(false)
+        })
+        .collect();
+
+    backups.sort();
+    backups.reverse();
+
+    Ok(backups)
+}
+
+pub fn backup(path: &Path) -> io::Result<Option<PathBuf>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let backup_dir = get_backup_dir(path);
+    let timestamp = Utc::now().format(BACKUP_DATE_FORMAT).to_string();
+    let backup_path = with_extension(
+        &backup_dir.join(path.file_name().unwrap_or_default()),
+        &timestamp,
+    );
+
+    fs::create_dir_all(&backup_dir)?;
+    write_atomic(&backup_path, &fs::read(path)?)?;
+
+    for old_backup in list_backups(path)?.iter().skip(BACKUP_COUNT) {
+        fs::remove_file(old_backup)?;
+    }
+
+    Ok(Some(backup_path))
+}
+
+pub fn restore(path: &Path, backup_path: &Path) -> io::Result<()> {
+    let contents = fs::read(backup_path)?;
+
+    backup(path)?;
+    write_atomic(path, &contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::get_temp_path;
+
+    #[test]
+    fn test_backups_rotate_and_restore() {
+        let path = get_temp_path("storage_backups");
+
+        assert_eq!(backup(&path).unwrap(), None);
+
+        for index in 0..BACKUP_COUNT + 2 {
+            fs::write(&path, index.to_string()).unwrap();
+            backup(&path).unwrap();
+            std::thread::sleep(std::time::Duration::from_millis(2));
+        }
+
+        let backups = list_backups(&path).unwrap();
+
+        assert_eq!(backups.len(), BACKUP_COUNT);
+        assert_eq!(
+            fs::read_to_string(&backups[0]).unwrap(),
+            (BACKUP_COUNT + 1).to_string()
+        );
+
+        let oldest = backups.last().unwrap();
+
+        restore(&path, oldest).unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "2");
+        assert_eq!(
+            fs::read_to_string(&list_backups(&path).unwrap()[0]).unwrap(),
+            (BACKUP_COUNT + 1).to_string()
+        );
+    }
+}
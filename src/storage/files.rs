@@ -0,0 +1,106 @@
+use crate::app::tabs::todo::DATE_FORMAT;
+
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDate;
+
+const REMINDED_EXTENSION: &str = "reminded";
+const TEMP_EXTENSION: &str = "tmp";
+
+pub(super) fn with_extension(path: &Path, extension: &str) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_owned();
+
+    file_name.push(".");
+    file_name.push(extension);
+
+    path.with_file_name(file_name)
+}
+
+pub fn read_reminded_date(path: &Path) -> Option<NaiveDate> {
+    let text = fs::read_to_string(with_extension(path, REMINDED_EXTENSION)).ok()?;
+
+    NaiveDate::parse_from_str(text.trim(), DATE_FORMAT).ok()
+}
+
+pub fn write_reminded_date(path: &Path, date: NaiveDate) -> io::Result<()> {
+    write_atomic(
+        &with_extension(path, REMINDED_EXTENSION),
+        date.format(DATE_FORMAT).to_string().as_bytes(),
+    )
+}
+
+fn sync_dir(path: &Path) -> io::Result<()> {
+    match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => File::open(dir)?.sync_all(),
+        _ => Ok(()),
+    }
+}
+
+// --- Note ---
+// Contents are written to a temporary file next to the target first and then renamed over it, so a
+// crash or a full disk never leaves a half written todo list behind. The rename would replace a
+// symlink and the permissions of the file, so the link is followed and the permissions are copied
+pub fn write_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let path = fs::canonicalize(path).unwrap_or_else(|_| path.to_owned());
+    let temp_path = with_extension(&path, TEMP_EXTENSION);
+    let permissions = fs::metadata(&path).map(|metadata| metadata.permissions());
+
+    let result = (|| {
+        let mut file = File::create(&temp_path)?;
+
+        if let Ok(permissions) = permissions {
+            file.set_permissions(permissions)?;
+        }
+
+        file.write_all(contents)?;
+        file.sync_all()?;
+
+        fs::rename(&temp_path, &path)?;
+        sync_dir(&path)
+    })();
+
+    if result.is_err() && temp_path.exists() {
+        let _ = fs::remove_file(&temp_path);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::get_temp_path;
+
+    use std::os::unix::fs::PermissionsExt;
+
+    #[test]
+    fn test_write_atomic() {
+        let path = get_temp_path("storage_atomic");
+
+        write_atomic(&path, b"Walk").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "Walk");
+        assert!(!with_extension(&path, TEMP_EXTENSION).exists());
+    }
+
+    #[test]
+    fn test_write_atomic_keeps_symlink_and_permissions() {
+        let path = get_temp_path("storage_link");
+        let target = path.with_file_name("target.txt");
+
+        write_atomic(&target, b"Walk").unwrap();
+        fs::set_permissions(&target, fs::Permissions::from_mode(0o600)).unwrap();
+        std::os::unix::fs::symlink(&target, &path).unwrap();
+
+        write_atomic(&path, b"Pay rent").unwrap();
+
+        assert!(fs::symlink_metadata(&path).unwrap().is_symlink());
+        assert_eq!(fs::read_to_string(&target).unwrap(), "Pay rent");
+        assert_eq!(
+            fs::metadata(&target).unwrap().permissions().mode() & 0o777,
+            0o600
+        );
+    }
+}
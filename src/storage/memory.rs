@@ -0,0 +1,61 @@
+use super::{not_found, ParseError, TodoStore};
+use crate::app::tabs::todo::TodoItem;
+
+use std::cell::RefCell;
+use std::io;
+use std::rc::Rc;
+
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStore {
+    todo_list: Rc<RefCell<Vec<TodoItem>>>,
+}
+
+impl MemoryStore {
+    pub fn new(todo_list: Vec<TodoItem>) -> Self {
+        MemoryStore {
+            todo_list: Rc::new(RefCell::new(todo_list)),
+        }
+    }
+
+    pub fn get_todo_list(&self) -> Vec<TodoItem> {
+        self.todo_list.borrow().clone()
+    }
+}
+
+impl TodoStore for MemoryStore {
+    fn load(&self) -> io::Result<(Vec<TodoItem>, Vec<ParseError>)> {
+        Ok((self.get_todo_list(), Vec::new()))
+    }
+
+    fn save(&self, todo_list: &[TodoItem]) -> io::Result<()> {
+        *self.todo_list.borrow_mut() = todo_list.to_vec();
+        Ok(())
+    }
+
+    fn insert(&self, item: &TodoItem) -> io::Result<()> {
+        self.todo_list.borrow_mut().push(item.clone());
+        Ok(())
+    }
+
+    fn update(&self, item: &TodoItem) -> io::Result<()> {
+        let mut todo_list = self.todo_list.borrow_mut();
+        let todo = todo_list
+            .iter_mut()
+            .find(|todo| todo.id == item.id)
+            .ok_or_else(|| not_found(item.id))?;
+
+        *todo = item.clone();
+        Ok(())
+    }
+
+    fn delete(&self, id: u16) -> io::Result<()> {
+        let mut todo_list = self.todo_list.borrow_mut();
+        let index = todo_list
+            .iter()
+            .position(|todo| todo.id == id)
+            .ok_or_else(|| not_found(id))?;
+
+        todo_list.remove(index);
+        Ok(())
+    }
+}
@@ -0,0 +1,396 @@
+use super::{ParseError, TodoStore};
+use crate::app::reminder::{DecisionRecord, DECISION_SEPARATOR};
+use crate::app::tabs::todo::{Priority, Status, TodoItem, DATE_FORMAT};
+
+use std::cell::Cell;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use core::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+use rusqlite::{params, Connection, Row};
+
+const SCHEMA_VERSION: u32 = 3;
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS todos (
+        id INTEGER PRIMARY KEY,
+        position INTEGER NOT NULL,
+        date_created TEXT NOT NULL,
+        date_modified TEXT NOT NULL,
+        status TEXT NOT NULL,
+        description TEXT NOT NULL,
+        due TEXT,
+        scheduled TEXT,
+        priority TEXT,
+        date_completed TEXT,
+        decisions TEXT
+    );
+";
+// --- Note ---
+// Migration at index `n` upgrades the schema from version `n + 1`, new databases are created with
+// the latest schema right away
+const MIGRATIONS: [&str; 2] = [
+    "ALTER TABLE todos ADD COLUMN date_completed TEXT;",
+    "ALTER TABLE todos ADD COLUMN decisions TEXT;",
+];
+const INDICES: &str = "
+    CREATE INDEX IF NOT EXISTS todos_position ON todos (position);
+    CREATE INDEX IF NOT EXISTS todos_status ON todos (status);
+    CREATE INDEX IF NOT EXISTS todos_date_created ON todos (date_created);
+    CREATE INDEX IF NOT EXISTS todos_date_modified ON todos (date_modified);
+    CREATE INDEX IF NOT EXISTS todos_due ON todos (due);
+    CREATE INDEX IF NOT EXISTS todos_scheduled ON todos (scheduled);
+    CREATE INDEX IF NOT EXISTS todos_date_completed ON todos (date_completed);
+";
+const COLUMNS: &str = "id, date_created, date_modified, status, description, due, scheduled, \
+    priority, date_completed, decisions";
+
+fn to_io_error(err: rusqlite::Error) -> io::Error {
+    io::Error::other(err)
+}
+
+fn invalid_data(id: u16, value: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("todo '{id}' has invalid value '{value}'"),
+    )
+}
+
+type Fields = (
+    u16,
+    String,
+    String,
+    String,
+    String,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+);
+
+fn read_row(row: &Row) -> rusqlite::Result<Fields> {
+    Ok((
+        row.get(0)?,
+        row.get(1)?,
+        row.get(2)?,
+        row.get(3)?,
+        row.get(4)?,
+        row.get(5)?,
+        row.get(6)?,
+        row.get(7)?,
+        row.get(8)?,
+        row.get(9)?,
+    ))
+}
+
+fn parse_fields(fields: Fields) -> io::Result<TodoItem> {
+    let (
+        id,
+        date_created,
+        date_modified,
+        status,
+        description,
+        due,
+        scheduled,
+        priority,
+        date_completed,
+        decisions,
+    ) = fields;
+
+    let parse_date_time = |value: &str| {
+        DateTime::parse_from_rfc3339(value)
+            .map(|date| date.with_timezone(&Utc))
+            .map_err(|_| invalid_data(id, value))
+    };
+    let parse_date = |value: Option<String>| {
+        value
+            .map(|value| {
+                NaiveDate::parse_from_str(&value, DATE_FORMAT).map_err(|_| invalid_data(id, &value))
+            })
+            .transpose()
+    };
+
+    let mut item = TodoItem::new(id, &description);
+
+    item.date_created = parse_date_time(&date_created)?;
+    item.date_modified = parse_date_time(&date_modified)?;
+    item.status = Status::from_str(&status).map_err(|_| invalid_data(id, &status))?;
+    item.due = parse_date(due)?;
+    item.scheduled = parse_date(scheduled)?;
+    item.priority = priority
+        .map(|value| Priority::from_str(&value).map_err(|_| invalid_data(id, &value)))
+        .transpose()?;
+    item.date_completed = date_completed
+        .map(|value| parse_date_time(&value))
+        .transpose()?;
+    item.decisions = decisions
+        .iter()
+        .flat_map(|value| value.split(DECISION_SEPARATOR))
+        .map(|value| DecisionRecord::from_str(value).map_err(|_| invalid_data(id, value)))
+        .collect::<io::Result<Vec<DecisionRecord>>>()?;
+
+    Ok(item)
+}
+
+fn format_decisions(decisions: &[DecisionRecord]) -> Option<String> {
+    if decisions.is_empty() {
+        return None;
+    }
+
+    let records: Vec<String> = decisions.iter().map(|record| record.to_string()).collect();
+
+    Some(records.join(DECISION_SEPARATOR))
+}
+
+fn insert(connection: &Connection, item: &TodoItem, position: i64) -> rusqlite::Result<usize> {
+    connection.execute(
+        &format!(
+            "INSERT INTO todos (position, {COLUMNS}) \
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)"
+        ),
+        params![
+            position,
+            item.id(),
+            item.date_created.to_rfc3339(),
+            item.date_modified.to_rfc3339(),
+            item.status.as_str(),
+            item.description,
+            item.due.map(|date| date.format(DATE_FORMAT).to_string()),
+            item.scheduled
+                .map(|date| date.format(DATE_FORMAT).to_string()),
+            item.priority.map(|priority| priority.as_str()),
+            item.date_completed.map(|date| date.to_rfc3339()),
+            format_decisions(&item.decisions),
+        ],
+    )
+}
+
+#[derive(Debug)]
+pub struct SqliteStore {
+    connection: Connection,
+    path: PathBuf,
+    is_backed_up: Cell<bool>,
+}
+
+impl SqliteStore {
+    pub fn open(path: &Path) -> io::Result<Self> {
+        if let Some(prefix) = path.parent() {
+            std::fs::create_dir_all(prefix)?;
+        }
+
+        let connection = Connection::open(path).map_err(to_io_error)?;
+
+        let version: u32 = connection
+            .pragma_query_value(None, "user_version", |row| row.get(0))
+            .map_err(to_io_error)?;
+
+        connection.execute_batch(SCHEMA).map_err(to_io_error)?;
+
+        if version > 0 {
+            for migration in MIGRATIONS.iter().skip(version as usize - 1) {
+                connection.execute_batch(migration).map_err(to_io_error)?;
+            }
+        }
+
+        connection.execute_batch(INDICES).map_err(to_io_error)?;
+        connection
+            .pragma_update(None, "user_version", SCHEMA_VERSION)
+            .map_err(to_io_error)?;
+
+        Ok(SqliteStore {
+            connection,
+            path: path.to_owned(),
+            is_backed_up: Cell::new(false),
+        })
+    }
+
+    pub fn is_empty(&self) -> io::Result<bool> {
+        self.connection
+            .query_row("SELECT NOT EXISTS (SELECT 1 FROM todos)", [], |row| {
+                row.get(0)
+            })
+            .map_err(to_io_error)
+    }
+
+    fn backup(&self) -> io::Result<()> {
+        if !self.is_backed_up.get() {
+            super::backups::backup(&self.path)?;
+            self.is_backed_up.set(true);
+        }
+
+        Ok(())
+    }
+}
+
+impl TodoStore for SqliteStore {
+    fn load(&self) -> io::Result<(Vec<TodoItem>, Vec<ParseError>)> {
+        let mut statement = self
+            .connection
+            .prepare(&format!("SELECT {COLUMNS} FROM todos ORDER BY position"))
+            .map_err(to_io_error)?;
+        let rows = statement
+            .query_map([], read_row)
+            .map_err(to_io_error)?
+            .collect::<rusqlite::Result<Vec<Fields>>>()
+            .map_err(to_io_error)?;
+
+        let todo_list = rows
+            .into_iter()
+            .map(parse_fields)
+            .collect::<io::Result<Vec<TodoItem>>>()?;
+
+        Ok((todo_list, Vec::new()))
+    }
+
+    fn save(&self, todo_list: &[TodoItem]) -> io::Result<()> {
+        self.backup()?;
+
+        let transaction = self
+            .connection
+            .unchecked_transaction()
+            .map_err(to_io_error)?;
+
+        transaction
+            .execute("DELETE FROM todos", [])
+            .map_err(to_io_error)?;
+
+        for (position, item) in todo_list.iter().enumerate() {
+            insert(&transaction, item, position as i64).map_err(to_io_error)?;
+        }
+
+        transaction.commit().map_err(to_io_error)
+    }
+
+    fn insert(&self, item: &TodoItem) -> io::Result<()> {
+        self.backup()?;
+
+        let position: Option<i64> = self
+            .connection
+            .query_row("SELECT MAX(position) FROM todos", [], |row| row.get(0))
+            .map_err(to_io_error)?;
+
+        insert(
+            &self.connection,
+            item,
+            position.map_or(0, |position| position + 1),
+        )
+        .map_err(to_io_error)?;
+
+        Ok(())
+    }
+
+    fn update(&self, item: &TodoItem) -> io::Result<()> {
+        self.backup()?;
+
+        let count = self
+            .connection
+            .execute(
+                "UPDATE todos SET date_created = ?2, date_modified = ?3, status = ?4,
+                    description = ?5, due = ?6, scheduled = ?7, priority = ?8, date_completed = ?9,
+                    decisions = ?10
+                WHERE id = ?1",
+                params![
+                    item.id(),
+                    item.date_created.to_rfc3339(),
+                    item.date_modified.to_rfc3339(),
+                    item.status.as_str(),
+                    item.description,
+                    item.due.map(|date| date.format(DATE_FORMAT).to_string()),
+                    item.scheduled
+                        .map(|date| date.format(DATE_FORMAT).to_string()),
+                    item.priority.map(|priority| priority.as_str()),
+                    item.date_completed.map(|date| date.to_rfc3339()),
+                    format_decisions(&item.decisions),
+                ],
+            )
+            .map_err(to_io_error)?;
+
+        match count {
+            0 => Err(super::not_found(item.id())),
+            _ => Ok(()),
+        }
+    }
+
+    fn delete(&self, id: u16) -> io::Result<()> {
+        self.backup()?;
+
+        let count = self
+            .connection
+            .execute("DELETE FROM todos WHERE id = ?1", params![id])
+            .map_err(to_io_error)?;
+
+        match count {
+            0 => Err(super::not_found(id)),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::get_temp_path;
+
+    #[test]
+    fn test_sqlite_store_changes() {
+        let path = get_temp_path("sqlite").with_file_name("todo.db");
+
+        let store = SqliteStore::open(&path).unwrap();
+        let mut first = TodoItem::new(4, "Pay rent");
+        let second = TodoItem::new(1, "Walk");
+
+        first.due = NaiveDate::from_ymd_opt(2026, 11, 3);
+        first.priority = Some(Priority::A);
+        first.decisions = vec![
+            DecisionRecord::from_str("2026-10-18 carry").unwrap(),
+            DecisionRecord::from_str("2026-10-19 reschedule 2026-10-25").unwrap(),
+        ];
+
+        assert!(store.is_empty().unwrap());
+
+        store.insert(&first).unwrap();
+        store.insert(&second).unwrap();
+        store.insert(&TodoItem::new(7, "Call mom")).unwrap();
+
+        first.set_status(Status::Done);
+        store.update(&first).unwrap();
+        store.delete(7).unwrap();
+
+        assert_eq!(store.load().unwrap().0, vec![first.clone(), second.clone()]);
+        assert!(store.delete(7).is_err());
+
+        store.save(&[second.clone(), first.clone()]).unwrap();
+
+        assert_eq!(
+            SqliteStore::open(&path).unwrap().load().unwrap().0,
+            vec![second, first]
+        );
+    }
+
+    #[test]
+    fn test_sqlite_migrates_schema() {
+        let path = get_temp_path("sqlite_schema").with_file_name("todo.db");
+
+        let connection = Connection::open(&path).unwrap();
+
+        connection
+            .execute_batch(&SCHEMA.replace(
+                ",\n        date_completed TEXT,\n        decisions TEXT",
+                "",
+            ))
+            .unwrap();
+        connection.pragma_update(None, "user_version", 1).unwrap();
+        drop(connection);
+
+        let store = SqliteStore::open(&path).unwrap();
+        let mut item = TodoItem::new(0, "Walk");
+
+        item.set_status(Status::Done);
+        store.insert(&item).unwrap();
+
+        assert_eq!(store.load().unwrap().0, vec![item]);
+    }
+}
@@ -0,0 +1,593 @@
+use super::backups::backup;
+use super::files::{with_extension, write_atomic};
+use super::{not_found, TodoStore};
+use crate::app::reminder::DecisionRecord;
+use crate::app::tabs::todo::{Priority, Status, TodoItem, DATE_FORMAT};
+
+use std::cell::Cell;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use core::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+const DELIMITER: char = '|';
+const ESCAPE: char = '\\';
+const FIELD_COUNT: usize = 5;
+const COMMENT: &str = "#";
+const ATTRIBUTE_SEPARATOR: char = ':';
+const DUE_ATTRIBUTE: &str = "due";
+const SCHEDULED_ATTRIBUTE: &str = "scheduled";
+const PRIORITY_ATTRIBUTE: &str = "priority";
+const COMPLETED_ATTRIBUTE: &str = "completed";
+const DECISION_ATTRIBUTE: &str = "decision";
+// --- Note ---
+// Files without the header are written by older versions which didn't escape the delimiter
+const FORMAT_HEADER: &str = "# mindr todo list, format 2";
+const REJECTED_EXTENSION: &str = "rejected";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    Encoding { line: usize },
+    FieldCount { line: usize, found: usize },
+    InvalidId { line: usize, value: String },
+    DuplicateId { line: usize, id: u16 },
+    InvalidDate { line: usize, value: String },
+    InvalidStatus { line: usize, value: String },
+    InvalidAttribute { line: usize, value: String },
+}
+
+impl ParseError {
+    pub fn line(&self) -> usize {
+        match self {
+            ParseError::Encoding { line }
+            | ParseError::FieldCount { line, .. }
+            | ParseError::InvalidId { line, .. }
+            | ParseError::DuplicateId { line, .. }
+            | ParseError::InvalidDate { line, .. }
+            | ParseError::InvalidStatus { line, .. }
+            | ParseError::InvalidAttribute { line, .. } => *line,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Encoding { line } => write!(f, "line {line}: not a valid UTF-8 text"),
+            ParseError::FieldCount { line, found } => write!(
+                f,
+                "line {line}: expected at least {FIELD_COUNT} fields 'id|DateTime|DateTime|Status|Description', found {found}"
+            ),
+            ParseError::InvalidId { line, value } => {
+                write!(f, "line {line}: '{value}' is not a valid id")
+            }
+            ParseError::DuplicateId { line, id } => {
+                write!(f, "line {line}: id '{id}' is already used by another todo")
+            }
+            ParseError::InvalidDate { line, value } => {
+                write!(f, "line {line}: '{value}' is not a valid date")
+            }
+            ParseError::InvalidStatus { line, value } => {
+                write!(f, "line {line}: '{value}' is not a valid status, try using 'Todo/Done'")
+            }
+            ParseError::InvalidAttribute { line, value } => {
+                write!(f, "line {line}: '{value}' is not a known 'key:value' field")
+            }
+        }
+    }
+}
+
+fn parse_date(value: &str, line: usize) -> Result<DateTime<Utc>, ParseError> {
+    value
+        .parse::<DateTime<Utc>>()
+        .map_err(|_| ParseError::InvalidDate {
+            line,
+            value: value.to_owned(),
+        })
+}
+
+fn parse_attribute_date(value: &str, field: &str, line: usize) -> Result<NaiveDate, ParseError> {
+    NaiveDate::parse_from_str(value, DATE_FORMAT).map_err(|_| ParseError::InvalidAttribute {
+        line,
+        value: field.to_owned(),
+    })
+}
+
+fn escape(field: &str) -> String {
+    let mut escaped = String::with_capacity(field.len());
+
+    for ch in field.chars() {
+        match ch {
+            ESCAPE | DELIMITER => {
+                escaped.push(ESCAPE);
+                escaped.push(ch);
+            }
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            _ => escaped.push(ch),
+        }
+    }
+
+    escaped
+}
+
+fn split_escaped(text: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut chars = text.chars();
+
+    while let Some(ch) = chars.next() {
+        let field = fields.last_mut().unwrap();
+
+        match ch {
+            ESCAPE => match chars.next() {
+                Some('n') => field.push('\n'),
+                Some('r') => field.push('\r'),
+                Some(next @ (ESCAPE | DELIMITER)) => field.push(next),
+                Some(next) => {
+                    field.push(ESCAPE);
+                    field.push(next);
+                }
+                None => field.push(ESCAPE),
+            },
+            DELIMITER => fields.push(String::new()),
+            _ => field.push(ch),
+        }
+    }
+
+    fields
+}
+
+fn split_legacy(text: &str) -> Vec<String> {
+    text.splitn(FIELD_COUNT, DELIMITER)
+        .map(String::from)
+        .collect()
+}
+
+pub fn parse_line(text: &str, line: usize) -> Result<TodoItem, ParseError> {
+    parse_fields(split_escaped(text), line)
+}
+
+fn parse_fields(fields: Vec<String>, line: usize) -> Result<TodoItem, ParseError> {
+    if fields.len() < FIELD_COUNT {
+        return Err(ParseError::FieldCount {
+            line,
+            found: fields.len(),
+        });
+    }
+
+    let id = fields[0]
+        .parse::<u16>()
+        .map_err(|_| ParseError::InvalidId {
+            line,
+            value: fields[0].to_owned(),
+        })?;
+    let date_created = parse_date(&fields[1], line)?;
+    let date_modified = parse_date(&fields[2], line)?;
+    let status = Status::from_str(&fields[3]).map_err(|_| ParseError::InvalidStatus {
+        line,
+        value: fields[3].to_owned(),
+    })?;
+    let description = fields[4].to_owned();
+
+    let mut due = None;
+    let mut scheduled = None;
+    let mut priority = None;
+    let mut date_completed = None;
+    let mut decisions = Vec::new();
+
+    for field in &fields[FIELD_COUNT..] {
+        let invalid = || ParseError::InvalidAttribute {
+            line,
+            value: field.to_owned(),
+        };
+        let (key, value) = field.split_once(ATTRIBUTE_SEPARATOR).ok_or_else(invalid)?;
+
+        match key {
+            DUE_ATTRIBUTE => due = Some(parse_attribute_date(value, field, line)?),
+            SCHEDULED_ATTRIBUTE => scheduled = Some(parse_attribute_date(value, field, line)?),
+            PRIORITY_ATTRIBUTE => {
+                priority = Some(Priority::from_str(value).map_err(|_| invalid())?)
+            }
+            COMPLETED_ATTRIBUTE => {
+                date_completed = Some(parse_date(value, line).map_err(|_| invalid())?)
+            }
+            DECISION_ATTRIBUTE => {
+                decisions.push(DecisionRecord::from_str(value).map_err(|_| invalid())?)
+            }
+            _ => return Err(invalid()),
+        }
+    }
+
+    Ok(TodoItem {
+        id,
+        date_created,
+        date_modified,
+        status,
+        description,
+        due,
+        scheduled,
+        priority,
+        date_completed,
+        decisions,
+    })
+}
+
+pub fn format_line(item: &TodoItem) -> String {
+    let TodoItem {
+        id,
+        date_created,
+        date_modified,
+        status,
+        description,
+        due,
+        scheduled,
+        priority,
+        date_completed,
+        decisions,
+    } = item;
+
+    let mut fields = vec![
+        id.to_string(),
+        date_created.to_string(),
+        date_modified.to_string(),
+        status.as_str().to_owned(),
+        escape(description),
+    ];
+
+    for (key, date) in [(DUE_ATTRIBUTE, due), (SCHEDULED_ATTRIBUTE, scheduled)] {
+        if let Some(date) = date {
+            fields.push(format!(
+                "{key}{ATTRIBUTE_SEPARATOR}{}",
+                date.format(DATE_FORMAT)
+            ));
+        }
+    }
+
+    if let Some(priority) = priority {
+        fields.push(format!(
+            "{PRIORITY_ATTRIBUTE}{ATTRIBUTE_SEPARATOR}{}",
+            priority.as_str()
+        ));
+    }
+
+    if let Some(date) = date_completed {
+        fields.push(format!("{COMPLETED_ATTRIBUTE}{ATTRIBUTE_SEPARATOR}{date}"));
+    }
+
+    for record in decisions {
+        fields.push(format!("{DECISION_ATTRIBUTE}{ATTRIBUTE_SEPARATOR}{record}"));
+    }
+
+    fields.join(&DELIMITER.to_string())
+}
+
+pub fn format_file(todo_list: &[TodoItem]) -> String {
+    let mut lines = vec![FORMAT_HEADER.to_owned()];
+
+    lines.extend(todo_list.iter().map(format_line));
+
+    lines.join("\n")
+}
+
+pub fn get_rejected_path(path: &Path) -> PathBuf {
+    with_extension(path, REJECTED_EXTENSION)
+}
+
+pub fn save(path: &Path, todo_list: &[TodoItem]) -> io::Result<()> {
+    write_atomic(path, format_file(todo_list).as_bytes())
+}
+
+fn quarantine(path: &Path, rejected: &[(ParseError, &[u8])]) -> io::Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(get_rejected_path(path))?;
+    let now = Utc::now();
+
+    for (error, line) in rejected {
+        writeln!(file, "# {now} {error}")?;
+        file.write_all(line)?;
+        writeln!(file)?;
+    }
+
+    file.sync_all()
+}
+
+pub fn load(path: &Path) -> io::Result<(Vec<TodoItem>, Vec<ParseError>)> {
+    let contents = fs::read(path)?;
+
+    let mut todo_list: Vec<TodoItem> = Vec::new();
+    let mut rejected: Vec<(ParseError, &[u8])> = Vec::new();
+    let mut is_legacy = true;
+
+    for (index, bytes) in contents.split(|byte| *byte == b'\n').enumerate() {
+        let line = index + 1;
+
+        let item = match std::str::from_utf8(bytes) {
+            Ok(text) if text.trim() == FORMAT_HEADER => {
+                is_legacy = false;
+                continue;
+            }
+            Ok(text) if text.trim().is_empty() || text.starts_with(COMMENT) => continue,
+            Ok(text) if is_legacy => parse_fields(split_legacy(text.trim_end_matches('\r')), line),
+            Ok(text) => parse_line(text.trim_end_matches('\r'), line),
+            Err(_) => Err(ParseError::Encoding { line }),
+        };
+
+        let item = item.and_then(|item| {
+            if todo_list.iter().any(|todo| todo.id == item.id) {
+                return Err(ParseError::DuplicateId { line, id: item.id });
+            }
+
+            Ok(item)
+        });
+
+        match item {
+            Ok(item) => todo_list.push(item),
+            Err(error) => rejected.push((error, bytes)),
+        }
+    }
+
+    if !rejected.is_empty() {
+        quarantine(path, &rejected)?;
+    }
+
+    let errors = rejected.into_iter().map(|(error, _)| error).collect();
+
+    Ok((todo_list, errors))
+}
+
+#[derive(Debug)]
+pub struct TextStore {
+    path: PathBuf,
+    is_backed_up: Cell<bool>,
+}
+
+impl TextStore {
+    pub fn new(path: &Path) -> Self {
+        TextStore {
+            path: path.to_owned(),
+            is_backed_up: Cell::new(false),
+        }
+    }
+
+    fn change(&self, apply: impl FnOnce(&mut Vec<TodoItem>) -> io::Result<()>) -> io::Result<()> {
+        let (mut todo_list, _) = load(&self.path)?;
+
+        apply(&mut todo_list)?;
+        self.save(&todo_list)
+    }
+}
+
+impl TodoStore for TextStore {
+    fn load(&self) -> io::Result<(Vec<TodoItem>, Vec<ParseError>)> {
+        if !self.path.exists() {
+            if let Some(prefix) = self.path.parent() {
+                fs::create_dir_all(prefix)?;
+            }
+
+            File::create(&self.path)?;
+        }
+
+        load(&self.path)
+    }
+
+    fn save(&self, todo_list: &[TodoItem]) -> io::Result<()> {
+        if !self.is_backed_up.get() {
+            backup(&self.path)?;
+            self.is_backed_up.set(true);
+        }
+
+        save(&self.path, todo_list)
+    }
+
+    fn insert(&self, item: &TodoItem) -> io::Result<()> {
+        self.change(|todo_list| {
+            todo_list.push(item.clone());
+            Ok(())
+        })
+    }
+
+    fn update(&self, item: &TodoItem) -> io::Result<()> {
+        self.change(|todo_list| {
+            let todo = todo_list
+                .iter_mut()
+                .find(|todo| todo.id == item.id)
+                .ok_or_else(|| not_found(item.id))?;
+
+            *todo = item.clone();
+            Ok(())
+        })
+    }
+
+    fn delete(&self, id: u16) -> io::Result<()> {
+        self.change(|todo_list| {
+            let index = todo_list
+                .iter()
+                .position(|todo| todo.id == id)
+                .ok_or_else(|| not_found(id))?;
+
+            todo_list.remove(index);
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::get_temp_path;
+
+    #[test]
+    fn test_parse_line() {
+        let line =
+            "3|2022-10-20 15:32:01.585089387 UTC|2022-10-21 15:32:01.585089387 UTC|Done|Walk";
+        let item = parse_line(line, 1).unwrap();
+
+        assert_eq!(item.id, 3);
+        assert_eq!(item.status, Status::Done);
+        assert_eq!(item.description, "Walk");
+        assert_eq!(format_line(&item), line);
+    }
+
+    #[test]
+    fn test_parse_attributes() {
+        let date = "2022-10-20 15:32:01.585089387 UTC";
+        let line =
+            format!("3|{date}|{date}|Done|Pay rent|due:2022-11-03|scheduled:2022-11-01|priority:B|completed:{date}|decision:2022-11-02 carry|decision:2022-11-03 drop");
+        let item = parse_line(&line, 1).unwrap();
+
+        assert_eq!(item.due, NaiveDate::from_ymd_opt(2022, 11, 3));
+        assert_eq!(item.scheduled, NaiveDate::from_ymd_opt(2022, 11, 1));
+        assert_eq!(item.priority, Some(Priority::B));
+        assert_eq!(item.date_completed, Some(item.date_created));
+        assert_eq!(item.decisions.len(), 2);
+        assert_eq!(format_line(&item), line);
+
+        assert_eq!(
+            parse_line(&format!("3|{date}|{date}|Todo|Pay rent|due:someday"), 5),
+            Err(ParseError::InvalidAttribute {
+                line: 5,
+                value: String::from("due:someday")
+            })
+        );
+        assert_eq!(
+            parse_line(&format!("3|{date}|{date}|Todo|Pay rent|color:red"), 6),
+            Err(ParseError::InvalidAttribute {
+                line: 6,
+                value: String::from("color:red")
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let date = "2022-10-20 15:32:01.585089387 UTC";
+
+        assert_eq!(
+            parse_line("1|Todo|Walk", 4),
+            Err(ParseError::FieldCount { line: 4, found: 3 })
+        );
+        assert_eq!(
+            parse_line(&format!("x|{date}|{date}|Todo|Walk"), 1),
+            Err(ParseError::InvalidId {
+                line: 1,
+                value: String::from("x")
+            })
+        );
+        assert_eq!(
+            parse_line(&format!("1|yesterday|{date}|Todo|Walk"), 2),
+            Err(ParseError::InvalidDate {
+                line: 2,
+                value: String::from("yesterday")
+            })
+        );
+        assert_eq!(
+            parse_line(&format!("1|{date}|{date}|Later|Walk"), 3),
+            Err(ParseError::InvalidStatus {
+                line: 3,
+                value: String::from("Later")
+            })
+        );
+    }
+
+    #[test]
+    fn test_round_trip_escaping() {
+        let descriptions = [
+            "echo hi | grep h",
+            "| a | b |",
+            "C:\\Users\\me\\",
+            "trailing \\",
+            "\\| not a delimiter",
+            "first\nsecond\r\nthird",
+            "literal \\n stays",
+        ];
+
+        for (id, description) in descriptions.iter().enumerate() {
+            let item = TodoItem::new(id as u16, description);
+            let line = format_line(&item);
+
+            assert!(!line.contains('\n'));
+            assert_eq!(parse_line(&line, 1), Ok(item));
+        }
+    }
+
+    #[test]
+    fn test_load_migrates_legacy_file() {
+        let path = get_temp_path("storage_legacy");
+        let date = "2022-10-20 15:32:01.585089387 UTC";
+        let contents = [
+            format!("0|{date}|{date}|Todo|cat log | grep error"),
+            format!("1|{date}|{date}|Done|C:\\temp"),
+        ];
+
+        fs::write(&path, contents.join("\n")).unwrap();
+
+        let (todo_list, errors) = load(&path).unwrap();
+
+        assert!(errors.is_empty());
+        assert_eq!(todo_list[0].description, "cat log | grep error");
+        assert_eq!(todo_list[1].description, "C:\\temp");
+
+        fs::write(&path, format_file(&todo_list)).unwrap();
+
+        let (migrated_list, errors) = load(&path).unwrap();
+
+        assert!(errors.is_empty());
+        assert_eq!(migrated_list, todo_list);
+    }
+
+    #[test]
+    fn test_load_quarantines_bad_lines() {
+        let path = get_temp_path("storage_quarantine");
+        let date = "2022-10-20 15:32:01.585089387 UTC";
+        let contents = [
+            format!("0|{date}|{date}|Todo|First"),
+            String::from("broken line"),
+            String::new(),
+            format!("0|{date}|{date}|Todo|Duplicate"),
+            format!("1|{date}|{date}|Done|Second"),
+        ];
+
+        fs::write(&path, format!("{FORMAT_HEADER}\n{}", contents.join("\n"))).unwrap();
+
+        let (todo_list, errors) = load(&path).unwrap();
+
+        assert_eq!(todo_list.len(), 2);
+        assert_eq!(
+            errors,
+            vec![
+                ParseError::FieldCount { line: 3, found: 1 },
+                ParseError::DuplicateId { line: 5, id: 0 },
+            ]
+        );
+
+        let rejected = fs::read_to_string(get_rejected_path(&path)).unwrap();
+
+        assert!(rejected.contains("\nbroken line\n"));
+        assert!(rejected.contains("|Todo|Duplicate\n"));
+    }
+
+    #[test]
+    fn test_text_store_changes() {
+        let path = get_temp_path("storage_store");
+        let store = TextStore::new(&path);
+        let mut item = TodoItem::new(0, "Walk");
+
+        assert_eq!(store.load().unwrap(), (Vec::new(), Vec::new()));
+
+        store.insert(&item).unwrap();
+        store.insert(&TodoItem::new(1, "Pay rent")).unwrap();
+
+        item.status = Status::Done;
+        store.update(&item).unwrap();
+        store.delete(1).unwrap();
+
+        assert_eq!(store.load().unwrap().0, vec![item]);
+        assert!(store.delete(1).is_err());
+    }
+}
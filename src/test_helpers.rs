@@ -0,0 +1,22 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+// --- Note ---
+// Every test gets an empty directory of its own, so the backups and rejected lines of the previous
+// runs don't get in the way
+fn get_temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join("mindr").join(format!("test_{name}"));
+
+    if dir.exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    fs::create_dir_all(&dir).unwrap();
+
+    dir
+}
+
+pub fn get_temp_path(name: &str) -> PathBuf {
+    get_temp_dir(name).join("todo.txt")
+}
@@ -0,0 +1,104 @@
+mod common;
+
+use common::get_temp_path;
+
+use std::env;
+use std::fs;
+use std::process::{Command, Output};
+
+fn mindr(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_mindr"))
+        .args(args)
+        .env_remove("MINDR_CONFIG")
+        .env_remove("MINDR_DATA")
+        .output()
+        .expect("Couldn't run mindr")
+}
+
+#[test]
+fn it_uses_data_flag() {
+    let path = get_temp_path("cli_flag");
+    let data = path.to_str().unwrap();
+
+    let output = mindr(&["--data", data, "add", "Buy milk"]);
+
+    assert!(output.status.success());
+    assert!(path.exists());
+
+    let output = mindr(&["--data", data, "list"]);
+
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "0\ttodo\tBuy milk\n"
+    );
+}
+
+#[test]
+fn it_uses_data_env() {
+    let path = get_temp_path("cli_env");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_mindr"))
+        .args(["add", "From env"])
+        .env("MINDR_DATA", &path)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert!(fs::read_to_string(&path).unwrap().contains("From env"));
+}
+
+#[test]
+fn it_works_without_home() {
+    let path = get_temp_path("cli_no_home");
+    let data = path.to_str().unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_mindr"))
+        .args(["--data", data, "list"])
+        .env_remove("HOME")
+        .env_remove("USERNAME")
+        .env_remove("XDG_CONFIG_HOME")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+}
+
+#[test]
+fn it_returns_exit_codes() {
+    let path = get_temp_path("cli_exit_codes");
+    let data = path.to_str().unwrap();
+
+    assert_eq!(mindr(&["--data", data, "rm", "42"]).status.code(), Some(1));
+    assert_eq!(mindr(&["--data", data, "rm"]).status.code(), Some(2));
+    assert_eq!(mindr(&["--data"]).status.code(), Some(2));
+
+    fs::write(&path, "").unwrap();
+
+    let nested = path.join("todo.txt");
+    let output = mindr(&["--data", nested.to_str().unwrap(), "add", "x"]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Couldn't read todo list"));
+}
+
+#[test]
+fn it_reports_missing_config_keys_once() {
+    let path = get_temp_path("cli_old_config");
+    let data = path.to_str().unwrap();
+    let config = env::temp_dir().join("mindr").join("test_cli_old.conf");
+
+    fs::create_dir_all(config.parent().unwrap()).unwrap();
+    fs::write(
+        &config,
+        "; kept\n[general]\ndisplay_todays=false\n[extra]\nkey=value\n",
+    )
+    .unwrap();
+
+    let args = ["--config", config.to_str().unwrap(), "--data", data, "list"];
+
+    assert!(!mindr(&args).stderr.is_empty());
+    assert!(mindr(&args).stderr.is_empty());
+    assert!(fs::read_to_string(&config)
+        .unwrap()
+        .starts_with("; kept\n[general]\ndisplay_todays=false\n[extra]\nkey=value\n"));
+}
@@ -0,0 +1,18 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+pub fn get_temp_path(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join("mindr");
+    let path = dir.join(format!("test_{name}.txt"));
+
+    fs::create_dir_all(&dir).unwrap();
+
+    for path in [path.clone(), dir.join(format!("test_{name}.txt.rejected"))] {
+        if path.exists() {
+            fs::remove_file(&path).unwrap();
+        }
+    }
+
+    path
+}
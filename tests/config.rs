@@ -1,25 +1,13 @@
 use mindr::app::selection::Selection;
 use mindr::config::Config;
+use mindr::storage::Backend;
 use serial_test::serial;
 
-use std::{env, fs};
 use std::path::PathBuf;
+use std::{env, fs};
 
 fn get_config_path() -> PathBuf {
-    // TODO: improve error handling
-    let user_name = env::var("USERNAME").expect("Couldn't get system user");
-
-    let path: PathBuf = [
-        "/home",
-        user_name.as_str(),
-        ".config",
-        "mindr",
-        "test_mindr.conf",
-    ]
-    .iter()
-    .collect();
-
-    path
+    env::temp_dir().join("mindr").join("test_mindr.conf")
 }
 
 #[test]
@@ -56,6 +44,8 @@ fn it_reads_existing_config() {
     config.auto_hide_menu = true;
     config.hide_menu_timeout = 1000;
     config.selection_style = Selection::Tilde;
+    config.priority_colors = false;
+    config.storage_backend = Backend::Sqlite;
 
     config.save();
 
@@ -68,6 +58,8 @@ fn it_reads_existing_config() {
         auto_hide_menu: true,
         hide_menu_timeout: 1000,
         selection_style: Selection::Tilde,
+        priority_colors: false,
+        storage_backend: Backend::Sqlite,
         ..Default::default()
     };
 